@@ -0,0 +1,746 @@
+use ion::{conversions::FromValue, typedarray::ArrayBuffer, Context, Object, Value};
+
+use super::{keystore_generate, keystore_import, resolve_exportable_bytes, resolve_key_bytes, CryptoAlgorithm};
+use crate::{
+    builtins::crypto::subtle::{
+        crypto_key::{CryptoKey, KeyFormat, KeyUsage},
+        HeapKeyData,
+    },
+    keystore::KeyStore,
+};
+
+// Mirrors Servo's approach of carrying the raw key bytes alongside the bit
+// length, rather than leaning on a generic crate wrapper for every variant.
+#[derive(Clone)]
+pub enum AesKey {
+    Aes128(Vec<u8>),
+    Aes192(Vec<u8>),
+    Aes256(Vec<u8>),
+}
+
+impl AesKey {
+    fn from_bytes(bytes: Vec<u8>) -> ion::Result<Self> {
+        match bytes.len() {
+            16 => Ok(AesKey::Aes128(bytes)),
+            24 => Ok(AesKey::Aes192(bytes)),
+            32 => Ok(AesKey::Aes256(bytes)),
+            len => Err(ion::Error::new(
+                &format!("{len} is not a valid AES key length, expected 16, 24, or 32 bytes"),
+                ion::ErrorKind::Normal,
+            )),
+        }
+    }
+
+    fn bytes(&self) -> &[u8] {
+        match self {
+            AesKey::Aes128(b) | AesKey::Aes192(b) | AesKey::Aes256(b) => b,
+        }
+    }
+
+    fn bits(&self) -> usize {
+        self.bytes().len() * 8
+    }
+}
+
+fn read_iv(params: &Object, cx: &Context, len_hint: &str) -> ion::Result<Vec<u8>> {
+    let iv = params.get(cx, "iv").ok_or_else(|| {
+        ion::Error::new(
+            &format!("AES-{len_hint} requires an `iv` parameter"),
+            ion::ErrorKind::Normal,
+        )
+    })?;
+    let iv = ArrayBuffer::from_value(cx, &iv, true, ()).map_err(|_| {
+        ion::Error::new("`iv` must be a BufferSource", ion::ErrorKind::Type)
+    })?;
+    Ok(iv.as_slice().to_vec())
+}
+
+fn pkcs7_pad(data: &mut Vec<u8>) {
+    let pad = 16 - (data.len() % 16);
+    data.extend(std::iter::repeat(pad as u8).take(pad));
+}
+
+fn pkcs7_unpad(data: &mut Vec<u8>) -> ion::Result<()> {
+    let pad = *data.last().ok_or_else(|| {
+        ion::Error::new("AES-CBC ciphertext is empty", ion::ErrorKind::Normal)
+    })? as usize;
+    if pad == 0 || pad > 16 || pad > data.len() {
+        return Err(ion::Error::new(
+            "AES-CBC padding is invalid",
+            ion::ErrorKind::Normal,
+        ));
+    }
+    let new_len = data.len() - pad;
+    if data[new_len..].iter().any(|&b| b as usize != pad) {
+        return Err(ion::Error::new(
+            "AES-CBC padding is invalid",
+            ion::ErrorKind::Normal,
+        ));
+    }
+    data.truncate(new_len);
+    Ok(())
+}
+
+pub struct AesGcm;
+pub struct AesCbc;
+pub struct AesCtr;
+pub struct AesKw;
+
+fn default_tag_length(cx: &Context, params: &Object) -> ion::Result<usize> {
+    Ok(params
+        .get(cx, "tagLength")
+        .and_then(|v| usize::from_value(cx, &v, true, ()).ok())
+        .unwrap_or(128))
+}
+
+// GCM's authentication tag is just the leftmost `TagSize` bytes of the full
+// 128-bit tag, so rather than computing a full tag and truncating (for
+// encrypt) or zero-padding a short tag back to 16 bytes (for decrypt, which
+// silently breaks authentication for every `tagLength < 128`), we select
+// the RustCrypto `AesGcm<Cipher, NonceSize, TagSize>` instantiation that
+// matches the caller's tag length directly. The crate does the truncated
+// comparison correctly; we just have to pick the right monomorphization.
+// This also gives us AES-192-GCM "for free" instead of special-casing it
+// out as unsupported.
+macro_rules! run_gcm {
+    ($op:ident, $Aes:ty, $bytes:expr, $tag_bytes:expr, $nonce:expr, $msg:expr, $aad:expr) => {{
+        use aes_gcm::aead::generic_array::typenum::{U12, U13, U14, U15, U16, U4, U8};
+        use aes_gcm::{aead::Aead, AesGcm};
+
+        macro_rules! op_with_tag {
+            ($TagSize:ty) => {{
+                let cipher = AesGcm::<$Aes, U12, $TagSize>::new_from_slice($bytes)
+                    .map_err(|_| ion::Error::new("Invalid AES-GCM key", ion::ErrorKind::Normal))?;
+                cipher.$op(
+                    aes_gcm::Nonce::<U12>::from_slice($nonce),
+                    aes_gcm::aead::Payload {
+                        msg: $msg,
+                        aad: $aad,
+                    },
+                )
+            }};
+        }
+
+        match $tag_bytes {
+            4 => op_with_tag!(U4),
+            8 => op_with_tag!(U8),
+            12 => op_with_tag!(U12),
+            13 => op_with_tag!(U13),
+            14 => op_with_tag!(U14),
+            15 => op_with_tag!(U15),
+            16 => op_with_tag!(U16),
+            other => {
+                return Err(ion::Error::new(
+                    &format!("Unsupported AES-GCM tag length: {} bits", other * 8),
+                    ion::ErrorKind::Normal,
+                ))
+            }
+        }
+    }};
+}
+
+impl CryptoAlgorithm for AesGcm {
+    fn name(&self) -> &'static str {
+        "AES-GCM"
+    }
+
+    fn encrypt<'cx>(
+        &self,
+        cx: &'cx Context,
+        params: &Object,
+        key: &CryptoKey,
+        data: Vec<u8>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        let aes_key = AesKey::from_bytes(resolve_key_bytes(key, keystore)?)?;
+        let iv = read_iv(params, cx, "GCM")?;
+        if iv.len() != 12 {
+            return Err(ion::Error::new(
+                "AES-GCM `iv` must be 12 bytes",
+                ion::ErrorKind::Normal,
+            ));
+        }
+        let aad = params
+            .get(cx, "additionalData")
+            .and_then(|v| ArrayBuffer::from_value(cx, &v, true, ()).ok())
+            .map(|b| b.as_slice().to_vec())
+            .unwrap_or_default();
+        let tag_bytes = default_tag_length(cx, params)? / 8;
+
+        let ciphertext = match &aes_key {
+            AesKey::Aes128(k) => run_gcm!(encrypt, aes::Aes128, k, tag_bytes, &iv, &data, &aad),
+            AesKey::Aes192(k) => run_gcm!(encrypt, aes::Aes192, k, tag_bytes, &iv, &data, &aad),
+            AesKey::Aes256(k) => run_gcm!(encrypt, aes::Aes256, k, tag_bytes, &iv, &data, &aad),
+        }
+        .map_err(|_| ion::Error::new("AES-GCM encryption failed", ion::ErrorKind::Normal))?;
+
+        Ok(ArrayBuffer::from(cx, ciphertext))
+    }
+
+    fn decrypt<'cx>(
+        &self,
+        cx: &'cx Context,
+        params: &Object,
+        key: &CryptoKey,
+        data: Vec<u8>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        let aes_key = AesKey::from_bytes(resolve_key_bytes(key, keystore)?)?;
+        let iv = read_iv(params, cx, "GCM")?;
+        if iv.len() != 12 {
+            return Err(ion::Error::new(
+                "AES-GCM `iv` must be 12 bytes",
+                ion::ErrorKind::Normal,
+            ));
+        }
+        let aad = params
+            .get(cx, "additionalData")
+            .and_then(|v| ArrayBuffer::from_value(cx, &v, true, ()).ok())
+            .map(|b| b.as_slice().to_vec())
+            .unwrap_or_default();
+        let tag_bytes = default_tag_length(cx, params)? / 8;
+
+        if data.len() < tag_bytes {
+            return Err(ion::Error::new(
+                "AES-GCM ciphertext is shorter than the tag",
+                ion::ErrorKind::Normal,
+            ));
+        }
+
+        let plaintext = match &aes_key {
+            AesKey::Aes128(k) => run_gcm!(decrypt, aes::Aes128, k, tag_bytes, &iv, &data, &aad),
+            AesKey::Aes192(k) => run_gcm!(decrypt, aes::Aes192, k, tag_bytes, &iv, &data, &aad),
+            AesKey::Aes256(k) => run_gcm!(decrypt, aes::Aes256, k, tag_bytes, &iv, &data, &aad),
+        }
+        .map_err(|_| {
+            ion::Error::new(
+                "AES-GCM authentication tag verification failed",
+                ion::ErrorKind::Normal,
+            )
+        })?;
+
+        Ok(ArrayBuffer::from(cx, plaintext))
+    }
+
+    fn generate_key(
+        &self,
+        cx: &Context,
+        params: &Object,
+        extractable: bool,
+        usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<CryptoKey> {
+        generate_aes_key(cx, params, extractable, usages, "AES-GCM", keystore)
+    }
+
+    fn import_key(
+        &self,
+        cx: &Context,
+        _params: &Object,
+        format: KeyFormat,
+        key_data: HeapKeyData,
+        extractable: bool,
+        usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<CryptoKey> {
+        import_aes_key(cx, format, key_data, extractable, usages, "AES-GCM", keystore)
+    }
+
+    fn export_key<'cx>(
+        &self,
+        cx: &'cx Context,
+        format: KeyFormat,
+        key: &CryptoKey,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<Value<'cx>> {
+        export_aes_key(cx, format, key, "AES-GCM", keystore)
+    }
+}
+
+impl CryptoAlgorithm for AesCbc {
+    fn name(&self) -> &'static str {
+        "AES-CBC"
+    }
+
+    fn encrypt<'cx>(
+        &self,
+        cx: &'cx Context,
+        params: &Object,
+        key: &CryptoKey,
+        mut data: Vec<u8>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        use aes::cipher::{block_padding::NoPadding, BlockEncryptMut, KeyIvInit};
+
+        let aes_key = AesKey::from_bytes(resolve_key_bytes(key, keystore)?)?;
+        let iv = read_iv(params, cx, "CBC")?;
+        if iv.len() != 16 {
+            return Err(ion::Error::new(
+                "AES-CBC `iv` must be 16 bytes",
+                ion::ErrorKind::Normal,
+            ));
+        }
+        pkcs7_pad(&mut data);
+
+        let out = match aes_key {
+            AesKey::Aes128(k) => {
+                let enc = cbc::Encryptor::<aes::Aes128>::new_from_slices(&k, &iv).unwrap();
+                enc.encrypt_padded_vec_mut::<NoPadding>(&data)
+            }
+            AesKey::Aes192(k) => {
+                let enc = cbc::Encryptor::<aes::Aes192>::new_from_slices(&k, &iv).unwrap();
+                enc.encrypt_padded_vec_mut::<NoPadding>(&data)
+            }
+            AesKey::Aes256(k) => {
+                let enc = cbc::Encryptor::<aes::Aes256>::new_from_slices(&k, &iv).unwrap();
+                enc.encrypt_padded_vec_mut::<NoPadding>(&data)
+            }
+        };
+
+        Ok(ArrayBuffer::from(cx, out))
+    }
+
+    fn decrypt<'cx>(
+        &self,
+        cx: &'cx Context,
+        params: &Object,
+        key: &CryptoKey,
+        data: Vec<u8>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        use aes::cipher::{block_padding::NoPadding, BlockDecryptMut, KeyIvInit};
+
+        let aes_key = AesKey::from_bytes(resolve_key_bytes(key, keystore)?)?;
+        let iv = read_iv(params, cx, "CBC")?;
+        if iv.len() != 16 {
+            return Err(ion::Error::new(
+                "AES-CBC `iv` must be 16 bytes",
+                ion::ErrorKind::Normal,
+            ));
+        }
+
+        let mut plaintext = match aes_key {
+            AesKey::Aes128(k) => cbc::Decryptor::<aes::Aes128>::new_from_slices(&k, &iv)
+                .unwrap()
+                .decrypt_padded_vec_mut::<NoPadding>(&data)
+                .map_err(|_| ion::Error::new("AES-CBC decryption failed", ion::ErrorKind::Normal))?,
+            AesKey::Aes192(k) => cbc::Decryptor::<aes::Aes192>::new_from_slices(&k, &iv)
+                .unwrap()
+                .decrypt_padded_vec_mut::<NoPadding>(&data)
+                .map_err(|_| ion::Error::new("AES-CBC decryption failed", ion::ErrorKind::Normal))?,
+            AesKey::Aes256(k) => cbc::Decryptor::<aes::Aes256>::new_from_slices(&k, &iv)
+                .unwrap()
+                .decrypt_padded_vec_mut::<NoPadding>(&data)
+                .map_err(|_| ion::Error::new("AES-CBC decryption failed", ion::ErrorKind::Normal))?,
+        };
+        pkcs7_unpad(&mut plaintext)?;
+
+        Ok(ArrayBuffer::from(cx, plaintext))
+    }
+
+    fn generate_key(
+        &self,
+        cx: &Context,
+        params: &Object,
+        extractable: bool,
+        usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<CryptoKey> {
+        generate_aes_key(cx, params, extractable, usages, "AES-CBC", keystore)
+    }
+
+    fn import_key(
+        &self,
+        cx: &Context,
+        _params: &Object,
+        format: KeyFormat,
+        key_data: HeapKeyData,
+        extractable: bool,
+        usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<CryptoKey> {
+        import_aes_key(cx, format, key_data, extractable, usages, "AES-CBC", keystore)
+    }
+
+    fn export_key<'cx>(
+        &self,
+        cx: &'cx Context,
+        format: KeyFormat,
+        key: &CryptoKey,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<Value<'cx>> {
+        export_aes_key(cx, format, key, "AES-CBC", keystore)
+    }
+}
+
+impl CryptoAlgorithm for AesCtr {
+    fn name(&self) -> &'static str {
+        "AES-CTR"
+    }
+
+    fn encrypt<'cx>(
+        &self,
+        cx: &'cx Context,
+        params: &Object,
+        key: &CryptoKey,
+        data: Vec<u8>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        ctr_crypt(cx, params, key, data, keystore)
+    }
+
+    fn decrypt<'cx>(
+        &self,
+        cx: &'cx Context,
+        params: &Object,
+        key: &CryptoKey,
+        data: Vec<u8>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        // CTR mode is its own inverse.
+        ctr_crypt(cx, params, key, data, keystore)
+    }
+
+    fn generate_key(
+        &self,
+        cx: &Context,
+        params: &Object,
+        extractable: bool,
+        usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<CryptoKey> {
+        generate_aes_key(cx, params, extractable, usages, "AES-CTR", keystore)
+    }
+
+    fn import_key(
+        &self,
+        cx: &Context,
+        _params: &Object,
+        format: KeyFormat,
+        key_data: HeapKeyData,
+        extractable: bool,
+        usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<CryptoKey> {
+        import_aes_key(cx, format, key_data, extractable, usages, "AES-CTR", keystore)
+    }
+
+    fn export_key<'cx>(
+        &self,
+        cx: &'cx Context,
+        format: KeyFormat,
+        key: &CryptoKey,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<Value<'cx>> {
+        export_aes_key(cx, format, key, "AES-CTR", keystore)
+    }
+}
+
+// Per the WebCrypto spec, `AesCtrParams.length` splits the 16-byte counter
+// block into a fixed left-hand nonce prefix and a right-hand counter of
+// exactly `length` bits: only those low `length` bits increment (wrapping
+// modulo 2^length) from block to block, while the prefix never changes.
+// `ctr::Ctr128BE` always treats the full 128 bits as the counter, so it
+// would let the increment carry into (and corrupt) the nonce prefix once
+// the low `length` bits wrap -- hence the manual block-at-a-time keystream
+// below instead of handing the whole buffer to a `StreamCipher`.
+fn increment_counter(block: &mut [u8; 16], length: u8) {
+    let mut bits_remaining = length as usize;
+    let mut carry = 1u16;
+    for byte in block.iter_mut().rev() {
+        if bits_remaining == 0 || carry == 0 {
+            break;
+        }
+        if bits_remaining >= 8 {
+            let sum = *byte as u16 + carry;
+            *byte = sum as u8;
+            carry = sum >> 8;
+            bits_remaining -= 8;
+        } else {
+            let mask = (1u16 << bits_remaining) - 1;
+            let sum = (*byte as u16 & mask) + carry;
+            *byte = (*byte & !(mask as u8)) | (sum as u8 & mask as u8);
+            carry = 0;
+            bits_remaining = 0;
+        }
+    }
+}
+
+fn ctr_crypt<'cx>(
+    cx: &'cx Context,
+    params: &Object,
+    key: &CryptoKey,
+    mut data: Vec<u8>,
+    keystore: &dyn KeyStore,
+) -> ion::Result<ArrayBuffer<'cx>> {
+    use aes::cipher::{generic_array::GenericArray, BlockEncrypt, KeyInit};
+
+    let aes_key = AesKey::from_bytes(resolve_key_bytes(key, keystore)?)?;
+    let counter = params.get(cx, "counter").ok_or_else(|| {
+        ion::Error::new(
+            "AES-CTR requires a `counter` parameter",
+            ion::ErrorKind::Normal,
+        )
+    })?;
+    let counter = ArrayBuffer::from_value(cx, &counter, true, ())
+        .map_err(|_| ion::Error::new("`counter` must be a BufferSource", ion::ErrorKind::Type))?;
+    let counter = counter.as_slice();
+    if counter.len() != 16 {
+        return Err(ion::Error::new(
+            "AES-CTR `counter` block must be 16 bytes",
+            ion::ErrorKind::Normal,
+        ));
+    }
+    let mut counter_block = [0u8; 16];
+    counter_block.copy_from_slice(counter);
+
+    let length = params.get(cx, "length").ok_or_else(|| {
+        ion::Error::new(
+            "AES-CTR requires a `length` parameter (bit length of the counter)",
+            ion::ErrorKind::Normal,
+        )
+    })?;
+    let length = u8::from_value(cx, &length, true, ()).map_err(|_| {
+        ion::Error::new("`length` must be an integer", ion::ErrorKind::Type)
+    })?;
+    if length == 0 || length > 128 {
+        return Err(ion::Error::new(
+            "AES-CTR `length` must be between 1 and 128",
+            ion::ErrorKind::Normal,
+        ));
+    }
+
+    for chunk in data.chunks_mut(16) {
+        let mut keystream = GenericArray::clone_from_slice(&counter_block);
+        match &aes_key {
+            AesKey::Aes128(k) => aes::Aes128::new_from_slice(k)
+                .unwrap()
+                .encrypt_block(&mut keystream),
+            AesKey::Aes192(k) => aes::Aes192::new_from_slice(k)
+                .unwrap()
+                .encrypt_block(&mut keystream),
+            AesKey::Aes256(k) => aes::Aes256::new_from_slice(k)
+                .unwrap()
+                .encrypt_block(&mut keystream),
+        }
+        for (byte, ks) in chunk.iter_mut().zip(keystream.iter()) {
+            *byte ^= ks;
+        }
+        increment_counter(&mut counter_block, length);
+    }
+
+    Ok(ArrayBuffer::from(cx, data))
+}
+
+fn generate_aes_key(
+    cx: &Context,
+    params: &Object,
+    extractable: bool,
+    usages: Vec<KeyUsage>,
+    algorithm: &'static str,
+    keystore: &dyn KeyStore,
+) -> ion::Result<CryptoKey> {
+    let length: usize = params
+        .get(cx, "length")
+        .and_then(|v| usize::from_value(cx, &v, true, ()).ok())
+        .unwrap_or(256);
+    if ![128, 192, 256].contains(&length) {
+        return Err(ion::Error::new(
+            "AES key `length` must be 128, 192, or 256",
+            ion::ErrorKind::Normal,
+        ));
+    }
+
+    let (handle, bytes) = keystore_generate(keystore, length / 8, extractable)?;
+
+    Ok(CryptoKey::new(algorithm, extractable, usages, bytes).with_handle(handle))
+}
+
+fn import_aes_key(
+    cx: &Context,
+    format: KeyFormat,
+    key_data: HeapKeyData,
+    extractable: bool,
+    usages: Vec<KeyUsage>,
+    algorithm: &'static str,
+    keystore: &dyn KeyStore,
+) -> ion::Result<CryptoKey> {
+    let bytes = match format {
+        KeyFormat::Raw => key_data.into_bytes(cx)?,
+        KeyFormat::Jwk => {
+            let jwk = key_data.into_jwk(cx)?;
+            jwk.symmetric_key_bytes()?
+        }
+        other => {
+            return Err(ion::Error::new(
+                &format!("AES keys cannot be imported in {other:?} format"),
+                ion::ErrorKind::Normal,
+            ))
+        }
+    };
+    AesKey::from_bytes(bytes.clone())?;
+
+    let (handle, bytes) = keystore_import(keystore, bytes, extractable)?;
+
+    Ok(CryptoKey::new(algorithm, extractable, usages, bytes).with_handle(handle))
+}
+
+fn export_aes_key<'cx>(
+    cx: &'cx Context,
+    format: KeyFormat,
+    key: &CryptoKey,
+    algorithm: &'static str,
+    keystore: &dyn KeyStore,
+) -> ion::Result<Value<'cx>> {
+    let bytes = resolve_exportable_bytes(key, keystore)?;
+    match format {
+        KeyFormat::Raw => Ok(ArrayBuffer::from(cx, bytes).as_value(cx)),
+        KeyFormat::Jwk => {
+            let aes_key = AesKey::from_bytes(bytes.clone())?;
+            Ok(super::jwk::symmetric_jwk(cx, &bytes, &format!("A{}{}", aes_key.bits(), &algorithm[4..])))
+        }
+        other => Err(ion::Error::new(
+            &format!("AES keys cannot be exported in {other:?} format"),
+            ion::ErrorKind::Normal,
+        )),
+    }
+}
+
+// RFC 3394 default integrity check register.
+const KW_DEFAULT_IV: u64 = 0xA6A6A6A6A6A6A6A6;
+
+impl CryptoAlgorithm for AesKw {
+    fn name(&self) -> &'static str {
+        "AES-KW"
+    }
+
+    fn wrap_key<'cx>(
+        &self,
+        cx: &'cx Context,
+        _params: &Object,
+        format: KeyFormat,
+        key: &CryptoKey,
+        wrapping_key: CryptoKey,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        let kek = AesKey::from_bytes(wrapping_key.handle().raw_bytes()?)?;
+        let plaintext = serialize_key_for_wrap(cx, format, key)?;
+        let wrapped = aes_key_wrap(&kek, &plaintext)?;
+        Ok(ArrayBuffer::from(cx, wrapped))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn unwrap_key<'cx>(
+        &self,
+        cx: &'cx Context,
+        _params: &Object,
+        _format: KeyFormat,
+        wrapped_key: Vec<u8>,
+        unwrapping_key: &CryptoKey,
+        _extractable: bool,
+        _usages: Vec<KeyUsage>,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        let kek = AesKey::from_bytes(unwrapping_key.handle().raw_bytes()?)?;
+        let unwrapped = aes_key_unwrap(&kek, &wrapped_key)?;
+        Ok(ArrayBuffer::from(cx, unwrapped))
+    }
+}
+
+fn serialize_key_for_wrap(cx: &Context, format: KeyFormat, key: &CryptoKey) -> ion::Result<Vec<u8>> {
+    match format {
+        KeyFormat::Raw | KeyFormat::Spki | KeyFormat::Pkcs8 => key.handle().raw_bytes(),
+        KeyFormat::Jwk => {
+            let value = key.export(cx, format)?;
+            serde_json::to_vec(&value).map_err(|e| {
+                ion::Error::new(&format!("failed to serialize JWK: {e}"), ion::ErrorKind::Normal)
+            })
+        }
+    }
+}
+
+fn aes_key_wrap(kek: &AesKey, plaintext: &[u8]) -> ion::Result<Vec<u8>> {
+    use aes::cipher::{generic_array::GenericArray, BlockEncrypt, KeyInit};
+
+    if plaintext.len() % 8 != 0 || plaintext.len() < 16 {
+        return Err(ion::Error::new(
+            "AES-KW plaintext must be a multiple of 8 bytes and at least 16 bytes long",
+            ion::ErrorKind::Normal,
+        ));
+    }
+    let n = plaintext.len() / 8;
+    let mut r: Vec<[u8; 8]> = plaintext.chunks(8).map(|c| c.try_into().unwrap()).collect();
+    let mut a = KW_DEFAULT_IV.to_be_bytes();
+
+    let encrypt_block = |kek: &AesKey, block: &mut [u8; 16]| {
+        let mut b = GenericArray::clone_from_slice(block);
+        match kek {
+            AesKey::Aes128(k) => aes::Aes128::new_from_slice(k).unwrap().encrypt_block(&mut b),
+            AesKey::Aes192(k) => aes::Aes192::new_from_slice(k).unwrap().encrypt_block(&mut b),
+            AesKey::Aes256(k) => aes::Aes256::new_from_slice(k).unwrap().encrypt_block(&mut b),
+        }
+        block.copy_from_slice(&b);
+    };
+
+    for j in 0..=5u64 {
+        for i in 1..=n {
+            let mut block = [0u8; 16];
+            block[..8].copy_from_slice(&a);
+            block[8..].copy_from_slice(&r[i - 1]);
+            encrypt_block(kek, &mut block);
+            let msb = u64::from_be_bytes(block[..8].try_into().unwrap()) ^ (n as u64 * j + i as u64);
+            a = msb.to_be_bytes();
+            r[i - 1].copy_from_slice(&block[8..]);
+        }
+    }
+
+    let mut out = Vec::with_capacity(8 + plaintext.len());
+    out.extend_from_slice(&a);
+    for block in r {
+        out.extend_from_slice(&block);
+    }
+    Ok(out)
+}
+
+fn aes_key_unwrap(kek: &AesKey, wrapped: &[u8]) -> ion::Result<Vec<u8>> {
+    use aes::cipher::{generic_array::GenericArray, BlockDecrypt, KeyInit};
+
+    if wrapped.len() % 8 != 0 || wrapped.len() < 24 {
+        return Err(ion::Error::new(
+            "AES-KW wrapped key has an invalid length",
+            ion::ErrorKind::Normal,
+        ));
+    }
+    let n = wrapped.len() / 8 - 1;
+    let mut a: [u8; 8] = wrapped[..8].try_into().unwrap();
+    let mut r: Vec<[u8; 8]> = wrapped[8..].chunks(8).map(|c| c.try_into().unwrap()).collect();
+
+    let decrypt_block = |kek: &AesKey, block: &mut [u8; 16]| {
+        let mut b = GenericArray::clone_from_slice(block);
+        match kek {
+            AesKey::Aes128(k) => aes::Aes128::new_from_slice(k).unwrap().decrypt_block(&mut b),
+            AesKey::Aes192(k) => aes::Aes192::new_from_slice(k).unwrap().decrypt_block(&mut b),
+            AesKey::Aes256(k) => aes::Aes256::new_from_slice(k).unwrap().decrypt_block(&mut b),
+        }
+        block.copy_from_slice(&b);
+    };
+
+    for j in (0..=5u64).rev() {
+        for i in (1..=n).rev() {
+            let msb = u64::from_be_bytes(a) ^ (n as u64 * j + i as u64);
+            let mut block = [0u8; 16];
+            block[..8].copy_from_slice(&msb.to_be_bytes());
+            block[8..].copy_from_slice(&r[i - 1]);
+            decrypt_block(kek, &mut block);
+            a.copy_from_slice(&block[..8]);
+            r[i - 1].copy_from_slice(&block[8..]);
+        }
+    }
+
+    if u64::from_be_bytes(a) != KW_DEFAULT_IV {
+        return Err(ion::Error::new(
+            "AES-KW integrity check failed",
+            ion::ErrorKind::Normal,
+        ));
+    }
+
+    Ok(r.into_iter().flatten().collect())
+}
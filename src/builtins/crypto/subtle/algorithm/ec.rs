@@ -0,0 +1,659 @@
+use ion::{conversions::FromValue, typedarray::ArrayBuffer, Context, Object, Value};
+
+use super::{keystore_generate, keystore_import, resolve_exportable_bytes, resolve_key_bytes, CryptoAlgorithm};
+use crate::{
+    builtins::crypto::subtle::{
+        crypto_key::{CryptoKey, KeyFormat, KeyUsage},
+        HeapKeyData,
+    },
+    keystore::KeyStore,
+};
+
+/// The NIST curves supported for ECDSA, plus Ed25519 which shares most of
+/// the sign/verify/import/export plumbing but has no `hash` parameter and
+/// a fixed field width.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EcCurve {
+    P256,
+    P384,
+    P521,
+    Ed25519,
+}
+
+impl EcCurve {
+    fn from_name(name: &str) -> ion::Result<Self> {
+        match name {
+            "P-256" => Ok(EcCurve::P256),
+            "P-384" => Ok(EcCurve::P384),
+            "P-521" => Ok(EcCurve::P521),
+            "Ed25519" => Ok(EcCurve::Ed25519),
+            other => Err(ion::Error::new(
+                &format!("Unsupported named curve `{other}`"),
+                ion::ErrorKind::Normal,
+            )),
+        }
+    }
+
+    // Byte length of a field element / coordinate for this curve.
+    pub(super) fn field_len(&self) -> usize {
+        match self {
+            EcCurve::P256 => 32,
+            EcCurve::P384 => 48,
+            // P-521's field elements are 521 bits, rounded up to bytes.
+            EcCurve::P521 => 66,
+            EcCurve::Ed25519 => 32,
+        }
+    }
+}
+
+pub(super) fn read_curve(cx: &Context, params: &Object) -> ion::Result<EcCurve> {
+    let name = params.get(cx, "namedCurve").ok_or_else(|| {
+        ion::Error::new(
+            "EC operations require a `namedCurve` parameter",
+            ion::ErrorKind::Normal,
+        )
+    })?;
+    let name = String::from_value(cx, &name, true, ()).map_err(|_| {
+        ion::Error::new("`namedCurve` must be a string", ion::ErrorKind::Type)
+    })?;
+    EcCurve::from_name(&name)
+}
+
+pub(super) fn read_hash_name(cx: &Context, params: &Object) -> ion::Result<String> {
+    let hash = params.get(cx, "hash").ok_or_else(|| {
+        ion::Error::new(
+            "ECDSA operations require a `hash` parameter",
+            ion::ErrorKind::Normal,
+        )
+    })?;
+    if hash.handle().is_object() {
+        let obj = hash.to_object(cx);
+        let name = obj.get(cx, "name").ok_or_else(|| {
+            ion::Error::new("`hash` object is missing a `name`", ion::ErrorKind::Normal)
+        })?;
+        String::from_value(cx, &name, true, ())
+            .map_err(|_| ion::Error::new("`hash.name` must be a string", ion::ErrorKind::Type))
+    } else {
+        String::from_value(cx, &hash, true, ())
+            .map_err(|_| ion::Error::new("`hash` must be a string", ion::ErrorKind::Type))
+    }
+}
+
+fn digest_with(hash_name: &str, data: &[u8]) -> ion::Result<Vec<u8>> {
+    use sha2::{Digest, Sha256, Sha384, Sha512};
+
+    Ok(match hash_name {
+        "SHA-256" => Sha256::digest(data).to_vec(),
+        "SHA-384" => Sha384::digest(data).to_vec(),
+        "SHA-512" => Sha512::digest(data).to_vec(),
+        other => {
+            return Err(ion::Error::new(
+                &format!("Unsupported digest algorithm `{other}` for ECDSA"),
+                ion::ErrorKind::Normal,
+            ))
+        }
+    })
+}
+
+pub struct Ecdsa;
+pub struct Ed25519;
+
+impl CryptoAlgorithm for Ecdsa {
+    fn name(&self) -> &'static str {
+        "ECDSA"
+    }
+
+    fn get_jwk_identifier(&self, key: &CryptoKey) -> ion::Result<&'static str> {
+        // Each NIST curve has its own JOSE `alg` value; Ed25519 keys never
+        // reach this impl (they go through the `Ed25519` algorithm below).
+        match key.handle().ec_curve()? {
+            EcCurve::P256 => Ok("ES256"),
+            EcCurve::P384 => Ok("ES384"),
+            EcCurve::P521 => Ok("ES512"),
+            EcCurve::Ed25519 => Err(ion::Error::new(
+                "Ed25519 keys do not have an ECDSA JWK identifier",
+                ion::ErrorKind::Normal,
+            )),
+        }
+    }
+
+    fn sign<'cx>(
+        &self,
+        cx: &'cx Context,
+        params: &Object,
+        key: &CryptoKey,
+        data: Vec<u8>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        use p256::ecdsa::{
+            signature::hazmat::PrehashSigner, Signature as P256Signature, SigningKey as P256Signing,
+        };
+        use p384::ecdsa::{Signature as P384Signature, SigningKey as P384Signing};
+        use p521::ecdsa::{Signature as P521Signature, SigningKey as P521Signing};
+
+        let curve = key.handle().ec_curve()?;
+        let hash_name = read_hash_name(cx, params)?;
+        let digest = digest_with(&hash_name, &data)?;
+        let private = resolve_key_bytes(key, keystore)?;
+
+        let raw_sig: Vec<u8> = match curve {
+            EcCurve::P256 => {
+                let signing = P256Signing::from_bytes(private.as_slice().into()).map_err(|_| {
+                    ion::Error::new("Invalid P-256 private key", ion::ErrorKind::Normal)
+                })?;
+                let sig: P256Signature = signing.sign_prehash(&digest).map_err(|_| {
+                    ion::Error::new("ECDSA signing failed", ion::ErrorKind::Normal)
+                })?;
+                sig.to_bytes().to_vec()
+            }
+            EcCurve::P384 => {
+                let signing = P384Signing::from_bytes(private.as_slice().into()).map_err(|_| {
+                    ion::Error::new("Invalid P-384 private key", ion::ErrorKind::Normal)
+                })?;
+                let sig: P384Signature = signing.sign_prehash(&digest).map_err(|_| {
+                    ion::Error::new("ECDSA signing failed", ion::ErrorKind::Normal)
+                })?;
+                sig.to_bytes().to_vec()
+            }
+            EcCurve::P521 => {
+                let signing = P521Signing::from_bytes(private.as_slice().into()).map_err(|_| {
+                    ion::Error::new("Invalid P-521 private key", ion::ErrorKind::Normal)
+                })?;
+                let sig: P521Signature = signing.sign_prehash(&digest).map_err(|_| {
+                    ion::Error::new("ECDSA signing failed", ion::ErrorKind::Normal)
+                })?;
+                sig.to_bytes().to_vec()
+            }
+            EcCurve::Ed25519 => {
+                return Err(ion::Error::new(
+                    "Use the Ed25519 algorithm to sign with an Ed25519 key",
+                    ion::ErrorKind::Normal,
+                ))
+            }
+        };
+
+        Ok(ArrayBuffer::from(cx, raw_sig))
+    }
+
+    fn verify(
+        &self,
+        cx: &Context,
+        params: &Object,
+        key: &CryptoKey,
+        signature: Vec<u8>,
+        data: Vec<u8>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<bool> {
+        use p256::ecdsa::{
+            signature::hazmat::PrehashVerifier, Signature as P256Signature, VerifyingKey as P256Verifying,
+        };
+        use p384::ecdsa::{Signature as P384Signature, VerifyingKey as P384Verifying};
+        use p521::ecdsa::{Signature as P521Signature, VerifyingKey as P521Verifying};
+
+        let curve = key.handle().ec_curve()?;
+        let hash_name = read_hash_name(cx, params)?;
+        let digest = digest_with(&hash_name, &data)?;
+        let public = resolve_key_bytes(key, keystore)?;
+
+        let ok = match curve {
+            EcCurve::P256 => {
+                let sig = P256Signature::from_slice(&signature).map_err(|_| {
+                    ion::Error::new("Malformed ECDSA signature", ion::ErrorKind::Normal)
+                })?;
+                let verifying = P256Verifying::from_sec1_bytes(&public).map_err(|_| {
+                    ion::Error::new("Invalid P-256 public key", ion::ErrorKind::Normal)
+                })?;
+                verifying.verify_prehash(&digest, &sig).is_ok()
+            }
+            EcCurve::P384 => {
+                let sig = P384Signature::from_slice(&signature).map_err(|_| {
+                    ion::Error::new("Malformed ECDSA signature", ion::ErrorKind::Normal)
+                })?;
+                let verifying = P384Verifying::from_sec1_bytes(&public).map_err(|_| {
+                    ion::Error::new("Invalid P-384 public key", ion::ErrorKind::Normal)
+                })?;
+                verifying.verify_prehash(&digest, &sig).is_ok()
+            }
+            EcCurve::P521 => {
+                let sig = P521Signature::from_slice(&signature).map_err(|_| {
+                    ion::Error::new("Malformed ECDSA signature", ion::ErrorKind::Normal)
+                })?;
+                let verifying = P521Verifying::from_sec1_bytes(&public).map_err(|_| {
+                    ion::Error::new("Invalid P-521 public key", ion::ErrorKind::Normal)
+                })?;
+                verifying.verify_prehash(&digest, &sig).is_ok()
+            }
+            EcCurve::Ed25519 => {
+                return Err(ion::Error::new(
+                    "Use the Ed25519 algorithm to verify with an Ed25519 key",
+                    ion::ErrorKind::Normal,
+                ))
+            }
+        };
+
+        Ok(ok)
+    }
+
+    fn generate_key(
+        &self,
+        cx: &Context,
+        params: &Object,
+        extractable: bool,
+        usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<CryptoKey> {
+        let curve = read_curve(cx, params)?;
+        generate_ec_keypair(curve, extractable, usages, keystore)
+    }
+
+    fn import_key(
+        &self,
+        cx: &Context,
+        params: &Object,
+        format: KeyFormat,
+        key_data: HeapKeyData,
+        extractable: bool,
+        usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<CryptoKey> {
+        let curve = read_curve(cx, params)?;
+        import_ec_key(cx, curve, format, key_data, extractable, usages, keystore)
+    }
+
+    fn export_key<'cx>(
+        &self,
+        cx: &'cx Context,
+        format: KeyFormat,
+        key: &CryptoKey,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<Value<'cx>> {
+        export_ec_key(cx, format, key, keystore)
+    }
+}
+
+impl CryptoAlgorithm for Ed25519 {
+    fn name(&self) -> &'static str {
+        "Ed25519"
+    }
+
+    fn sign<'cx>(
+        &self,
+        cx: &'cx Context,
+        _params: &Object,
+        key: &CryptoKey,
+        data: Vec<u8>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let bytes = resolve_key_bytes(key, keystore)?;
+        let seed: [u8; 32] = bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| ion::Error::new("Invalid Ed25519 private key", ion::ErrorKind::Normal))?;
+        let signing = SigningKey::from_bytes(&seed);
+        let sig = signing.sign(&data);
+
+        Ok(ArrayBuffer::from(cx, sig.to_bytes().to_vec()))
+    }
+
+    fn verify(
+        &self,
+        _cx: &Context,
+        _params: &Object,
+        key: &CryptoKey,
+        signature: Vec<u8>,
+        data: Vec<u8>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<bool> {
+        use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+        let bytes = resolve_key_bytes(key, keystore)?;
+        let public: [u8; 32] = bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| ion::Error::new("Invalid Ed25519 public key", ion::ErrorKind::Normal))?;
+        let verifying = VerifyingKey::from_bytes(&public)
+            .map_err(|_| ion::Error::new("Invalid Ed25519 public key", ion::ErrorKind::Normal))?;
+        let sig = Signature::from_slice(&signature)
+            .map_err(|_| ion::Error::new("Malformed Ed25519 signature", ion::ErrorKind::Normal))?;
+
+        Ok(verifying.verify(&data, &sig).is_ok())
+    }
+
+    fn generate_key(
+        &self,
+        _cx: &Context,
+        _params: &Object,
+        extractable: bool,
+        usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<CryptoKey> {
+        generate_ec_keypair(EcCurve::Ed25519, extractable, usages, keystore)
+    }
+
+    fn import_key(
+        &self,
+        cx: &Context,
+        _params: &Object,
+        format: KeyFormat,
+        key_data: HeapKeyData,
+        extractable: bool,
+        usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<CryptoKey> {
+        import_ec_key(cx, EcCurve::Ed25519, format, key_data, extractable, usages, keystore)
+    }
+
+    fn export_key<'cx>(
+        &self,
+        cx: &'cx Context,
+        format: KeyFormat,
+        key: &CryptoKey,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<Value<'cx>> {
+        export_ec_key(cx, format, key, keystore)
+    }
+}
+
+fn generate_ec_keypair(
+    curve: EcCurve,
+    extractable: bool,
+    usages: Vec<KeyUsage>,
+    keystore: &dyn KeyStore,
+) -> ion::Result<CryptoKey> {
+    let (private, public): (Vec<u8>, Vec<u8>) = match curve {
+        EcCurve::P256 => {
+            let signing = p256::ecdsa::SigningKey::random(&mut rand::rngs::OsRng);
+            let verifying = signing.verifying_key();
+            (
+                signing.to_bytes().to_vec(),
+                verifying.to_sec1_bytes().to_vec(),
+            )
+        }
+        EcCurve::P384 => {
+            let signing = p384::ecdsa::SigningKey::random(&mut rand::rngs::OsRng);
+            let verifying = signing.verifying_key();
+            (
+                signing.to_bytes().to_vec(),
+                verifying.to_sec1_bytes().to_vec(),
+            )
+        }
+        EcCurve::P521 => {
+            let signing = p521::ecdsa::SigningKey::random(&mut rand::rngs::OsRng);
+            let verifying = signing.verifying_key();
+            (
+                signing.to_bytes().to_vec(),
+                verifying.to_sec1_bytes().to_vec(),
+            )
+        }
+        EcCurve::Ed25519 => {
+            let signing = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+            (
+                signing.to_bytes().to_vec(),
+                signing.verifying_key().to_bytes().to_vec(),
+            )
+        }
+    };
+
+    // Only the private scalar is sensitive enough to need keystore-backed
+    // storage; the public point is freely shareable and stays embedded in
+    // the `CryptoKey` the way it always has.
+    let (handle, private) = keystore_import(keystore, private, extractable)?;
+
+    Ok(CryptoKey::new_ec_pair(curve_name(curve), extractable, usages, private, public).with_handle(handle))
+}
+
+/// Rejects a private scalar/seed whose length doesn't match `curve`'s field
+/// width. Sign-time conversion to the RustCrypto signing key types uses a
+/// `GenericArray`-backed `from_bytes`/`try_into` that panics on a length
+/// mismatch, so script importing a malformed-length key (trivial via
+/// `importKey("jwk", {..., d: "short"}, ...)`) must be rejected here rather
+/// than being allowed to reach `sign`.
+fn validate_private_key_length(curve: EcCurve, bytes: &[u8]) -> ion::Result<()> {
+    if bytes.len() != curve.field_len() {
+        return Err(ion::Error::new(
+            &format!(
+                "Invalid private key length for {}: expected {} bytes, got {}",
+                curve_name(curve),
+                curve.field_len(),
+                bytes.len()
+            ),
+            ion::ErrorKind::Normal,
+        ));
+    }
+    Ok(())
+}
+
+fn import_ec_key(
+    cx: &Context,
+    curve: EcCurve,
+    format: KeyFormat,
+    key_data: HeapKeyData,
+    extractable: bool,
+    usages: Vec<KeyUsage>,
+    keystore: &dyn KeyStore,
+) -> ion::Result<CryptoKey> {
+    // Public points aren't sensitive and are kept embedded directly in the
+    // `CryptoKey`, as before; only private material gets registered with
+    // `keystore` so non-extractable private keys can't have their bytes
+    // read back out except through `KeyStore::export`.
+    match format {
+        KeyFormat::Raw => {
+            let bytes = key_data.into_bytes(cx)?;
+            CryptoKey::new_ec_public(curve_name(curve), extractable, usages, bytes)
+        }
+        KeyFormat::Spki => {
+            let der = key_data.into_bytes(cx)?;
+            let point = spki_to_raw_public(curve, &der)?;
+            CryptoKey::new_ec_public(curve_name(curve), extractable, usages, point)
+        }
+        KeyFormat::Pkcs8 => {
+            let der = key_data.into_bytes(cx)?;
+            let scalar = pkcs8_to_raw_private(curve, &der)?;
+            validate_private_key_length(curve, &scalar)?;
+            let (handle, scalar) = keystore_import(keystore, scalar, extractable)?;
+            CryptoKey::new_ec_private(curve_name(curve), extractable, usages, scalar)
+                .map(|key| key.with_handle(handle))
+        }
+        KeyFormat::Jwk => {
+            let jwk = key_data.into_jwk(cx)?;
+            if let Some(d) = jwk.field("d")? {
+                validate_private_key_length(curve, &d)?;
+                let (handle, d) = keystore_import(keystore, d, extractable)?;
+                CryptoKey::new_ec_private(curve_name(curve), extractable, usages, d)
+                    .map(|key| key.with_handle(handle))
+            } else {
+                let x = jwk.field_required("x")?;
+                if curve == EcCurve::Ed25519 {
+                    CryptoKey::new_ec_public(curve_name(curve), extractable, usages, x)
+                } else {
+                    let y = jwk.field_required("y")?;
+                    let mut point = Vec::with_capacity(1 + curve.field_len() * 2);
+                    point.push(0x04);
+                    point.extend_from_slice(&x);
+                    point.extend_from_slice(&y);
+                    CryptoKey::new_ec_public(curve_name(curve), extractable, usages, point)
+                }
+            }
+        }
+    }
+}
+
+fn export_ec_key<'cx>(
+    cx: &'cx Context,
+    format: KeyFormat,
+    key: &CryptoKey,
+    keystore: &dyn KeyStore,
+) -> ion::Result<Value<'cx>> {
+    let curve = key.handle().ec_curve()?;
+    let bytes = resolve_exportable_bytes(key, keystore)?;
+    match format {
+        KeyFormat::Raw => Ok(ArrayBuffer::from(cx, bytes).as_value(cx)),
+        KeyFormat::Spki => {
+            let der = raw_public_to_spki(curve, &bytes)?;
+            Ok(ArrayBuffer::from(cx, der).as_value(cx))
+        }
+        KeyFormat::Pkcs8 => {
+            let der = raw_private_to_pkcs8(curve, &bytes)?;
+            Ok(ArrayBuffer::from(cx, der).as_value(cx))
+        }
+        KeyFormat::Jwk => {
+            // Ed25519 public keys are a bare 32-byte value with no SEC1
+            // `0x04` prefix, unlike the NIST curves, so they need their own
+            // branch rather than `bytes[1..].split_at(field_len)`.
+            if curve == EcCurve::Ed25519 {
+                if key.handle().is_private() {
+                    let signing = ed25519_dalek::SigningKey::from_bytes(
+                        bytes.as_slice().try_into().map_err(|_| {
+                            ion::Error::new("Invalid Ed25519 private key", ion::ErrorKind::Normal)
+                        })?,
+                    );
+                    let public = signing.verifying_key().to_bytes();
+                    Ok(super::jwk::okp_private_jwk(cx, curve_name(curve), &public, &bytes))
+                } else {
+                    Ok(super::jwk::okp_public_jwk(cx, curve_name(curve), &bytes))
+                }
+            } else if key.handle().is_private() {
+                Ok(super::jwk::ec_private_jwk(cx, curve_name(curve), &bytes))
+            } else {
+                let field_len = curve.field_len();
+                let (x, y) = bytes[1..].split_at(field_len);
+                Ok(super::jwk::ec_public_jwk(cx, curve_name(curve), x, y))
+            }
+        }
+    }
+}
+
+/// Decodes an X.509 SubjectPublicKeyInfo DER document into this crate's
+/// internal raw-point representation (SEC1 uncompressed point for the NIST
+/// curves, bare 32 bytes for Ed25519).
+fn spki_to_raw_public(curve: EcCurve, der: &[u8]) -> ion::Result<Vec<u8>> {
+    use spki::DecodePublicKey;
+
+    let bad_key = || ion::Error::new("Invalid SPKI-encoded public key", ion::ErrorKind::Normal);
+    match curve {
+        EcCurve::P256 => Ok(p256::PublicKey::from_public_key_der(der)
+            .map_err(|_| bad_key())?
+            .to_sec1_bytes()
+            .to_vec()),
+        EcCurve::P384 => Ok(p384::PublicKey::from_public_key_der(der)
+            .map_err(|_| bad_key())?
+            .to_sec1_bytes()
+            .to_vec()),
+        EcCurve::P521 => Ok(p521::PublicKey::from_public_key_der(der)
+            .map_err(|_| bad_key())?
+            .to_sec1_bytes()
+            .to_vec()),
+        EcCurve::Ed25519 => Ok(ed25519_dalek::VerifyingKey::from_public_key_der(der)
+            .map_err(|_| bad_key())?
+            .to_bytes()
+            .to_vec()),
+    }
+}
+
+/// The reverse of [`spki_to_raw_public`]: encodes this crate's internal raw
+/// public point as an X.509 SubjectPublicKeyInfo DER document.
+fn raw_public_to_spki(curve: EcCurve, raw: &[u8]) -> ion::Result<Vec<u8>> {
+    use spki::EncodePublicKey;
+
+    let bad_key = || ion::Error::new("Invalid public key", ion::ErrorKind::Normal);
+    let der = match curve {
+        EcCurve::P256 => p256::PublicKey::from_sec1_bytes(raw)
+            .map_err(|_| bad_key())?
+            .to_public_key_der()
+            .map_err(|_| bad_key())?
+            .into_vec(),
+        EcCurve::P384 => p384::PublicKey::from_sec1_bytes(raw)
+            .map_err(|_| bad_key())?
+            .to_public_key_der()
+            .map_err(|_| bad_key())?
+            .into_vec(),
+        EcCurve::P521 => p521::PublicKey::from_sec1_bytes(raw)
+            .map_err(|_| bad_key())?
+            .to_public_key_der()
+            .map_err(|_| bad_key())?
+            .into_vec(),
+        EcCurve::Ed25519 => {
+            let bytes: [u8; 32] = raw.try_into().map_err(|_| bad_key())?;
+            ed25519_dalek::VerifyingKey::from_bytes(&bytes)
+                .map_err(|_| bad_key())?
+                .to_public_key_der()
+                .map_err(|_| bad_key())?
+                .into_vec()
+        }
+    };
+    Ok(der)
+}
+
+/// Decodes a PKCS#8 DER document into this crate's internal raw private
+/// scalar representation.
+fn pkcs8_to_raw_private(curve: EcCurve, der: &[u8]) -> ion::Result<Vec<u8>> {
+    use pkcs8::DecodePrivateKey;
+
+    let bad_key = || ion::Error::new("Invalid PKCS#8-encoded private key", ion::ErrorKind::Normal);
+    match curve {
+        EcCurve::P256 => Ok(p256::SecretKey::from_pkcs8_der(der)
+            .map_err(|_| bad_key())?
+            .to_bytes()
+            .to_vec()),
+        EcCurve::P384 => Ok(p384::SecretKey::from_pkcs8_der(der)
+            .map_err(|_| bad_key())?
+            .to_bytes()
+            .to_vec()),
+        EcCurve::P521 => Ok(p521::SecretKey::from_pkcs8_der(der)
+            .map_err(|_| bad_key())?
+            .to_bytes()
+            .to_vec()),
+        EcCurve::Ed25519 => Ok(ed25519_dalek::SigningKey::from_pkcs8_der(der)
+            .map_err(|_| bad_key())?
+            .to_bytes()
+            .to_vec()),
+    }
+}
+
+/// The reverse of [`pkcs8_to_raw_private`]: encodes this crate's internal
+/// raw private scalar as a PKCS#8 DER document.
+fn raw_private_to_pkcs8(curve: EcCurve, raw: &[u8]) -> ion::Result<Vec<u8>> {
+    use pkcs8::EncodePrivateKey;
+
+    let bad_key = || ion::Error::new("Invalid private key", ion::ErrorKind::Normal);
+    let der = match curve {
+        EcCurve::P256 => p256::SecretKey::from_bytes(raw.into())
+            .map_err(|_| bad_key())?
+            .to_pkcs8_der()
+            .map_err(|_| bad_key())?
+            .as_bytes()
+            .to_vec(),
+        EcCurve::P384 => p384::SecretKey::from_bytes(raw.into())
+            .map_err(|_| bad_key())?
+            .to_pkcs8_der()
+            .map_err(|_| bad_key())?
+            .as_bytes()
+            .to_vec(),
+        EcCurve::P521 => p521::SecretKey::from_bytes(raw.into())
+            .map_err(|_| bad_key())?
+            .to_pkcs8_der()
+            .map_err(|_| bad_key())?
+            .as_bytes()
+            .to_vec(),
+        EcCurve::Ed25519 => {
+            let seed: [u8; 32] = raw.try_into().map_err(|_| bad_key())?;
+            ed25519_dalek::SigningKey::from_bytes(&seed)
+                .to_pkcs8_der()
+                .map_err(|_| bad_key())?
+                .as_bytes()
+                .to_vec()
+        }
+    };
+    Ok(der)
+}
+
+fn curve_name(curve: EcCurve) -> &'static str {
+    match curve {
+        EcCurve::P256 => "P-256",
+        EcCurve::P384 => "P-384",
+        EcCurve::P521 => "P-521",
+        EcCurve::Ed25519 => "Ed25519",
+    }
+}
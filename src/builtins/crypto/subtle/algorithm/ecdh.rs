@@ -0,0 +1,124 @@
+use ion::{typedarray::ArrayBuffer, Context, Object};
+
+use super::{ec::EcCurve, resolve_key_bytes, CryptoAlgorithm};
+use crate::{builtins::crypto::subtle::crypto_key::CryptoKey, keystore::KeyStore};
+
+pub struct Ecdh;
+
+fn read_peer_public_key(cx: &Context, params: &Object) -> ion::Result<CryptoKey> {
+    let public = params.get(cx, "public").ok_or_else(|| {
+        ion::Error::new(
+            "ECDH requires a `public` CryptoKey parameter",
+            ion::ErrorKind::Normal,
+        )
+    })?;
+    CryptoKey::from_value(cx, &public).map_err(|_| {
+        ion::Error::new(
+            "`public` must be a CryptoKey",
+            ion::ErrorKind::Type,
+        )
+    })
+}
+
+fn shared_x_coordinate(
+    base_key: &CryptoKey,
+    peer: &CryptoKey,
+    keystore: &dyn KeyStore,
+) -> ion::Result<Vec<u8>> {
+    let curve = base_key.handle().ec_curve()?;
+    if peer.handle().ec_curve()? != curve {
+        return Err(ion::Error::new(
+            "ECDH `public` key curve does not match the base key",
+            ion::ErrorKind::Normal,
+        ));
+    }
+
+    let private = resolve_key_bytes(base_key, keystore)?;
+    let public = peer.handle().raw_bytes()?;
+
+    match curve {
+        EcCurve::P256 => {
+            use p256::{ecdh::diffie_hellman, PublicKey, SecretKey};
+
+            let secret = SecretKey::from_bytes(private.as_slice().into())
+                .map_err(|_| ion::Error::new("Invalid P-256 private key", ion::ErrorKind::Normal))?;
+            let public = PublicKey::from_sec1_bytes(&public)
+                .map_err(|_| ion::Error::new("Invalid P-256 public key", ion::ErrorKind::Normal))?;
+            let shared = diffie_hellman(secret.to_nonzero_scalar(), public.as_affine());
+            Ok(shared.raw_secret_bytes().to_vec())
+        }
+        EcCurve::P384 => {
+            use p384::{ecdh::diffie_hellman, PublicKey, SecretKey};
+
+            let secret = SecretKey::from_bytes(private.as_slice().into())
+                .map_err(|_| ion::Error::new("Invalid P-384 private key", ion::ErrorKind::Normal))?;
+            let public = PublicKey::from_sec1_bytes(&public)
+                .map_err(|_| ion::Error::new("Invalid P-384 public key", ion::ErrorKind::Normal))?;
+            let shared = diffie_hellman(secret.to_nonzero_scalar(), public.as_affine());
+            Ok(shared.raw_secret_bytes().to_vec())
+        }
+        EcCurve::P521 => {
+            use p521::{ecdh::diffie_hellman, PublicKey, SecretKey};
+
+            let secret = SecretKey::from_bytes(private.as_slice().into())
+                .map_err(|_| ion::Error::new("Invalid P-521 private key", ion::ErrorKind::Normal))?;
+            let public = PublicKey::from_sec1_bytes(&public)
+                .map_err(|_| ion::Error::new("Invalid P-521 public key", ion::ErrorKind::Normal))?;
+            let shared = diffie_hellman(secret.to_nonzero_scalar(), public.as_affine());
+            Ok(shared.raw_secret_bytes().to_vec())
+        }
+        EcCurve::Ed25519 => Err(ion::Error::new(
+            "ECDH is not defined for Ed25519; use X25519 instead",
+            ion::ErrorKind::Normal,
+        )),
+    }
+}
+
+impl CryptoAlgorithm for Ecdh {
+    fn name(&self) -> &'static str {
+        "ECDH"
+    }
+
+    fn derive_bits<'cx>(
+        &self,
+        cx: &'cx Context,
+        params: &Object,
+        base_key: CryptoKey,
+        length: Option<usize>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        let peer = read_peer_public_key(cx, params)?;
+        let secret = shared_x_coordinate(&base_key, &peer, keystore)?;
+
+        // A `null` `length` (our `None`) means "the whole coordinate",
+        // mirroring the spec's treatment of a null `length` in deriveBits.
+        // `Some(0)` is a distinct, valid request for zero bits of output
+        // and must not be folded into that same case.
+        let byte_len = match length {
+            None => secret.len(),
+            Some(length) => {
+                if length % 8 != 0 {
+                    return Err(ion::Error::new(
+                        "ECDH `length` must be a multiple of 8 bits",
+                        ion::ErrorKind::Normal,
+                    ));
+                }
+                length / 8
+            }
+        };
+
+        if byte_len > secret.len() {
+            return Err(ion::Error::new(
+                "ECDH `length` exceeds the field size of the curve",
+                ion::ErrorKind::Normal,
+            ));
+        }
+
+        Ok(ArrayBuffer::from(cx, secret[..byte_len].to_vec()))
+    }
+
+    fn get_key_length(&self, cx: &Context, params: &Object) -> ion::Result<usize> {
+        let curve = super::ec::read_curve(cx, params)?;
+        Ok(curve.field_len() * 8)
+    }
+}
@@ -0,0 +1,63 @@
+//! Small helpers for building the JWK `Value`s that `export_key` returns.
+//! JWK byte fields (`k`, `x`, `y`, `d`) are base64url-encoded without
+//! padding, per RFC 7517.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use ion::{Context, Object, Value};
+
+fn b64(bytes: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Builds a symmetric (`kty: "oct"`) JWK for an AES/HMAC-style raw key.
+pub fn symmetric_jwk<'cx>(cx: &'cx Context, bytes: &[u8], alg: &str) -> Value<'cx> {
+    let object = Object::new(cx);
+    object.set_as(cx, "kty", &"oct");
+    object.set_as(cx, "k", &b64(bytes));
+    object.set_as(cx, "alg", &alg);
+    object.set_as(cx, "ext", &true);
+    object.as_value(cx)
+}
+
+/// Builds a public (`kty: "EC"`) JWK from the curve's `x`/`y` coordinates.
+pub fn ec_public_jwk<'cx>(cx: &'cx Context, curve_name: &str, x: &[u8], y: &[u8]) -> Value<'cx> {
+    let object = Object::new(cx);
+    object.set_as(cx, "kty", &"EC");
+    object.set_as(cx, "crv", &curve_name);
+    object.set_as(cx, "x", &b64(x));
+    object.set_as(cx, "y", &b64(y));
+    object.set_as(cx, "ext", &true);
+    object.as_value(cx)
+}
+
+/// Builds a private (`kty: "EC"`) JWK from the curve's private scalar `d`.
+pub fn ec_private_jwk<'cx>(cx: &'cx Context, curve_name: &str, d: &[u8]) -> Value<'cx> {
+    let object = Object::new(cx);
+    object.set_as(cx, "kty", &"EC");
+    object.set_as(cx, "crv", &curve_name);
+    object.set_as(cx, "d", &b64(d));
+    object.set_as(cx, "ext", &true);
+    object.as_value(cx)
+}
+
+/// Builds an Octet Key Pair (`kty: "OKP"`) JWK, used for Ed25519 keys which
+/// are a single 32-byte value rather than an (x, y) curve point.
+pub fn okp_public_jwk<'cx>(cx: &'cx Context, crv: &str, x: &[u8]) -> Value<'cx> {
+    let object = Object::new(cx);
+    object.set_as(cx, "kty", &"OKP");
+    object.set_as(cx, "crv", &crv);
+    object.set_as(cx, "x", &b64(x));
+    object.set_as(cx, "ext", &true);
+    object.as_value(cx)
+}
+
+/// Builds a private Octet Key Pair JWK for an Ed25519 key.
+pub fn okp_private_jwk<'cx>(cx: &'cx Context, crv: &str, x: &[u8], d: &[u8]) -> Value<'cx> {
+    let object = Object::new(cx);
+    object.set_as(cx, "kty", &"OKP");
+    object.set_as(cx, "crv", &crv);
+    object.set_as(cx, "x", &b64(x));
+    object.set_as(cx, "d", &b64(d));
+    object.set_as(cx, "ext", &true);
+    object.as_value(cx)
+}
@@ -0,0 +1,236 @@
+use ion::{conversions::FromValue, typedarray::ArrayBuffer, Context, Object};
+
+use super::{keystore_import, resolve_key_bytes, CryptoAlgorithm};
+use crate::{
+    builtins::crypto::subtle::{
+        crypto_key::{CryptoKey, KeyFormat, KeyUsage},
+        HeapKeyData,
+    },
+    keystore::KeyStore,
+};
+
+pub struct Hkdf;
+pub struct Pbkdf2;
+
+fn read_bytes(cx: &Context, params: &Object, name: &str) -> ion::Result<Vec<u8>> {
+    let value = params.get(cx, name).ok_or_else(|| {
+        ion::Error::new(
+            &format!("Missing required `{name}` parameter"),
+            ion::ErrorKind::Normal,
+        )
+    })?;
+    let buf = ArrayBuffer::from_value(cx, &value, true, ()).map_err(|_| {
+        ion::Error::new(
+            &format!("`{name}` must be a BufferSource"),
+            ion::ErrorKind::Type,
+        )
+    })?;
+    Ok(buf.as_slice().to_vec())
+}
+
+fn read_hash_name(cx: &Context, params: &Object) -> ion::Result<String> {
+    super::ec::read_hash_name(cx, params)
+}
+
+macro_rules! hmac_extract_expand {
+    ($hash:ty, $salt:expr, $ikm:expr, $info:expr, $length:expr) => {{
+        use hmac::{Hmac, Mac};
+        type HmacHash = Hmac<$hash>;
+
+        let mut prk_mac =
+            HmacHash::new_from_slice($salt).map_err(|_| {
+                ion::Error::new("HKDF salt is invalid for this hash", ion::ErrorKind::Normal)
+            })?;
+        prk_mac.update($ikm);
+        let prk = prk_mac.finalize().into_bytes();
+
+        let hash_len = prk.len();
+        if $length > 255 * hash_len * 8 {
+            return Err(ion::Error::new(
+                "HKDF `length` is too large for the selected hash",
+                ion::ErrorKind::Normal,
+            ));
+        }
+
+        let mut okm = Vec::new();
+        let mut t = Vec::new();
+        let mut counter = 1u8;
+        while okm.len() * 8 < $length {
+            let mut mac = HmacHash::new_from_slice(&prk).unwrap();
+            mac.update(&t);
+            mac.update($info);
+            mac.update(&[counter]);
+            t = mac.finalize().into_bytes().to_vec();
+            okm.extend_from_slice(&t);
+            counter = counter.wrapping_add(1);
+        }
+        okm.truncate($length / 8);
+        okm
+    }};
+}
+
+impl CryptoAlgorithm for Hkdf {
+    fn name(&self) -> &'static str {
+        "HKDF"
+    }
+
+    fn import_key(
+        &self,
+        cx: &Context,
+        _params: &Object,
+        format: KeyFormat,
+        key_data: HeapKeyData,
+        extractable: bool,
+        usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<CryptoKey> {
+        if format != KeyFormat::Raw {
+            return Err(ion::Error::new(
+                "HKDF input key material must be imported in `raw` format",
+                ion::ErrorKind::Normal,
+            ));
+        }
+        if extractable {
+            return Err(ion::Error::new(
+                "HKDF keys must be non-extractable",
+                ion::ErrorKind::Normal,
+            ));
+        }
+        let ikm = key_data.into_bytes(cx)?;
+        let (handle, ikm) = keystore_import(keystore, ikm, false)?;
+        Ok(CryptoKey::new("HKDF", false, usages, ikm).with_handle(handle))
+    }
+
+    fn derive_bits<'cx>(
+        &self,
+        cx: &'cx Context,
+        params: &Object,
+        base_key: CryptoKey,
+        length: Option<usize>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        use sha2::{Sha256, Sha384, Sha512};
+
+        // Unlike ECDH, HKDF has no algorithm-default output length, so a
+        // `null` `length` from script is a required-argument error rather
+        // than a fallback.
+        let length = length.ok_or_else(|| {
+            ion::Error::new("HKDF requires an explicit `length`", ion::ErrorKind::Normal)
+        })?;
+        if length % 8 != 0 {
+            return Err(ion::Error::new(
+                "HKDF `length` must be a multiple of 8 bits",
+                ion::ErrorKind::Normal,
+            ));
+        }
+
+        let hash_name = read_hash_name(cx, params)?;
+        let salt = read_bytes(cx, params, "salt")?;
+        let info = read_bytes(cx, params, "info")?;
+        let ikm = resolve_key_bytes(&base_key, keystore)?;
+
+        let okm = match hash_name.as_str() {
+            "SHA-256" => hmac_extract_expand!(Sha256, &salt, &ikm, &info, length),
+            "SHA-384" => hmac_extract_expand!(Sha384, &salt, &ikm, &info, length),
+            "SHA-512" => hmac_extract_expand!(Sha512, &salt, &ikm, &info, length),
+            other => {
+                return Err(ion::Error::new(
+                    &format!("Unsupported digest algorithm `{other}` for HKDF"),
+                    ion::ErrorKind::Normal,
+                ))
+            }
+        };
+
+        Ok(ArrayBuffer::from(cx, okm))
+    }
+}
+
+impl CryptoAlgorithm for Pbkdf2 {
+    fn name(&self) -> &'static str {
+        "PBKDF2"
+    }
+
+    fn import_key(
+        &self,
+        cx: &Context,
+        _params: &Object,
+        format: KeyFormat,
+        key_data: HeapKeyData,
+        extractable: bool,
+        usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<CryptoKey> {
+        if format != KeyFormat::Raw {
+            return Err(ion::Error::new(
+                "PBKDF2 input key material must be imported in `raw` format",
+                ion::ErrorKind::Normal,
+            ));
+        }
+        if extractable {
+            return Err(ion::Error::new(
+                "PBKDF2 keys must be non-extractable",
+                ion::ErrorKind::Normal,
+            ));
+        }
+        let password = key_data.into_bytes(cx)?;
+        let (handle, password) = keystore_import(keystore, password, false)?;
+        Ok(CryptoKey::new("PBKDF2", false, usages, password).with_handle(handle))
+    }
+
+    fn derive_bits<'cx>(
+        &self,
+        cx: &'cx Context,
+        params: &Object,
+        base_key: CryptoKey,
+        length: Option<usize>,
+        keystore: &dyn KeyStore,
+    ) -> ion::Result<ArrayBuffer<'cx>> {
+        use sha2::{Sha256, Sha384, Sha512};
+
+        // PBKDF2 has no algorithm-default output length either; see the
+        // analogous check in `Hkdf::derive_bits` above.
+        let length = length.ok_or_else(|| {
+            ion::Error::new("PBKDF2 requires an explicit `length`", ion::ErrorKind::Normal)
+        })?;
+
+        let hash_name = read_hash_name(cx, params)?;
+        let salt = read_bytes(cx, params, "salt")?;
+        let iterations = params.get(cx, "iterations").ok_or_else(|| {
+            ion::Error::new(
+                "PBKDF2 requires an `iterations` parameter",
+                ion::ErrorKind::Normal,
+            )
+        })?;
+        let iterations = u32::from_value(cx, &iterations, true, ()).map_err(|_| {
+            ion::Error::new("`iterations` must be a positive integer", ion::ErrorKind::Type)
+        })?;
+        if iterations == 0 {
+            return Err(ion::Error::new(
+                "PBKDF2 `iterations` must be greater than zero",
+                ion::ErrorKind::Normal,
+            ));
+        }
+        let password = resolve_key_bytes(&base_key, keystore)?;
+
+        if length % 8 != 0 {
+            return Err(ion::Error::new(
+                "PBKDF2 `length` must be a multiple of 8 bits",
+                ion::ErrorKind::Normal,
+            ));
+        }
+        let mut out = vec![0u8; length / 8];
+        match hash_name.as_str() {
+            "SHA-256" => pbkdf2::pbkdf2_hmac::<Sha256>(&password, &salt, iterations, &mut out),
+            "SHA-384" => pbkdf2::pbkdf2_hmac::<Sha384>(&password, &salt, iterations, &mut out),
+            "SHA-512" => pbkdf2::pbkdf2_hmac::<Sha512>(&password, &salt, iterations, &mut out),
+            other => {
+                return Err(ion::Error::new(
+                    &format!("Unsupported digest algorithm `{other}` for PBKDF2"),
+                    ion::ErrorKind::Normal,
+                ))
+            }
+        }
+
+        Ok(ArrayBuffer::from(cx, out))
+    }
+}
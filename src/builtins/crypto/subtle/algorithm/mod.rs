@@ -1,6 +1,12 @@
+pub mod aes;
+pub mod ec;
+pub mod ecdh;
 pub mod hmac;
+pub mod jwk;
+pub mod kdf;
 pub mod md5;
 pub mod sha;
+pub mod wycheproof;
 
 use ion::{typedarray::ArrayBuffer, Context, Object, Value};
 
@@ -8,6 +14,73 @@ use super::{
     crypto_key::{CryptoKey, KeyFormat, KeyUsage},
     HeapKeyData,
 };
+use crate::keystore::{KeyHandle, KeyStore, StoredKey};
+
+/// Persists freshly-generated key bytes through `keystore`'s CSRNG-backed
+/// `generate` so the returned handle is the keystore's own record of the
+/// key, not just a copy of bytes it never saw.
+pub(super) fn keystore_generate(
+    keystore: &dyn KeyStore,
+    len: usize,
+    extractable: bool,
+) -> ion::Result<(KeyHandle, Vec<u8>)> {
+    let (handle, stored) = keystore
+        .generate(len, extractable)
+        .map_err(|e| ion::Error::new(&e.to_string(), ion::ErrorKind::Normal))?;
+    Ok((handle, stored.bytes))
+}
+
+/// Registers caller-supplied key bytes (from `importKey`) with `keystore`,
+/// returning the handle alongside the same bytes so callers can keep
+/// building the `CryptoKey` the way they already do.
+pub(super) fn keystore_import(
+    keystore: &dyn KeyStore,
+    bytes: Vec<u8>,
+    extractable: bool,
+) -> ion::Result<(KeyHandle, Vec<u8>)> {
+    let handle = keystore
+        .serialize(StoredKey {
+            bytes: bytes.clone(),
+            extractable,
+        })
+        .map_err(|e| ion::Error::new(&e.to_string(), ion::ErrorKind::Normal))?;
+    Ok((handle, bytes))
+}
+
+/// Resolves a `CryptoKey`'s material for internal use (sign/encrypt/derive),
+/// going through the keystore when the key carries a handle. Keys with no
+/// handle (e.g. the wycheproof harness, which builds `CryptoKey`s straight
+/// from test vectors without ever registering them) fall back to whatever
+/// bytes the key already embeds.
+pub(super) fn resolve_key_bytes(key: &CryptoKey, keystore: &dyn KeyStore) -> ion::Result<Vec<u8>> {
+    match key.key_handle() {
+        Some(handle) => keystore
+            .deserialize(handle)
+            .map_err(|e| ion::Error::new(&e.to_string(), ion::ErrorKind::Normal))?
+            .map(|stored| stored.bytes)
+            .ok_or_else(|| {
+                ion::Error::new("Key handle not found in keystore", ion::ErrorKind::Normal)
+            }),
+        None => key.handle().raw_bytes(),
+    }
+}
+
+/// Like [`resolve_key_bytes`], but for `exportKey`: goes through
+/// [`KeyStore::export`] so a non-extractable key's bytes can't be handed
+/// back to script even though internal operations can still read them via
+/// `resolve_key_bytes`.
+pub(super) fn resolve_exportable_bytes(
+    key: &CryptoKey,
+    keystore: &dyn KeyStore,
+) -> ion::Result<Vec<u8>> {
+    match key.key_handle() {
+        Some(handle) => keystore
+            .export(handle)
+            .map_err(|e| ion::Error::new(&e.to_string(), ion::ErrorKind::Normal))?
+            .ok_or_else(|| ion::Error::new("Key is not extractable", ion::ErrorKind::Normal)),
+        None => key.handle().raw_bytes(),
+    }
+}
 
 // Some of the functions in this trait have no implementation,
 // so we allow them to be unused for now. Should be removed once
@@ -16,7 +89,7 @@ use super::{
 pub trait CryptoAlgorithm {
     fn name(&self) -> &'static str;
 
-    fn get_jwk_identifier(&self) -> ion::Result<&'static str> {
+    fn get_jwk_identifier(&self, key: &CryptoKey) -> ion::Result<&'static str> {
         Err(ion::Error::new(
             "Operation not supported by the specified algorithm",
             ion::ErrorKind::Normal,
@@ -29,6 +102,7 @@ pub trait CryptoAlgorithm {
         params: &Object,
         key: &CryptoKey,
         data: Vec<u8>,
+        keystore: &dyn KeyStore,
     ) -> ion::Result<ArrayBuffer<'cx>> {
         Err(ion::Error::new(
             "Operation not supported by the specified algorithm",
@@ -42,6 +116,7 @@ pub trait CryptoAlgorithm {
         params: &Object,
         key: &CryptoKey,
         data: Vec<u8>,
+        keystore: &dyn KeyStore,
     ) -> ion::Result<ArrayBuffer<'cx>> {
         Err(ion::Error::new(
             "Operation not supported by the specified algorithm",
@@ -55,6 +130,7 @@ pub trait CryptoAlgorithm {
         params: &Object,
         key: &CryptoKey,
         data: Vec<u8>,
+        keystore: &dyn KeyStore,
     ) -> ion::Result<ArrayBuffer<'cx>> {
         Err(ion::Error::new(
             "Operation not supported by the specified algorithm",
@@ -69,6 +145,7 @@ pub trait CryptoAlgorithm {
         key: &CryptoKey,
         signature: Vec<u8>,
         data: Vec<u8>,
+        keystore: &dyn KeyStore,
     ) -> ion::Result<bool> {
         Err(ion::Error::new(
             "Operation not supported by the specified algorithm",
@@ -88,12 +165,18 @@ pub trait CryptoAlgorithm {
         ))
     }
 
+    /// `length` is `None` when script passed `null`, which per the WebCrypto
+    /// spec means "use this algorithm's default output length" rather than
+    /// "derive zero bits" -- algorithms that have no such default (HKDF,
+    /// PBKDF2) should treat `None` as a required-argument error instead of
+    /// reinterpreting it as zero.
     fn derive_bits<'cx>(
         &self,
         cx: &'cx Context,
         params: &Object,
         base_key: CryptoKey,
-        length: usize,
+        length: Option<usize>,
+        keystore: &dyn KeyStore,
     ) -> ion::Result<ArrayBuffer<'cx>> {
         Err(ion::Error::new(
             "Operation not supported by the specified algorithm",
@@ -138,6 +221,7 @@ pub trait CryptoAlgorithm {
         params: &Object,
         extractable: bool,
         usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
     ) -> ion::Result<CryptoKey> {
         Err(ion::Error::new(
             "Operation not supported by the specified algorithm",
@@ -153,6 +237,7 @@ pub trait CryptoAlgorithm {
         key_data: HeapKeyData,
         extractable: bool,
         usages: Vec<KeyUsage>,
+        keystore: &dyn KeyStore,
     ) -> ion::Result<CryptoKey> {
         Err(ion::Error::new(
             "Operation not supported by the specified algorithm",
@@ -165,6 +250,7 @@ pub trait CryptoAlgorithm {
         cx: &'cx Context,
         format: KeyFormat,
         key: &CryptoKey,
+        keystore: &dyn KeyStore,
     ) -> ion::Result<Value<'cx>> {
         Err(ion::Error::new(
             "Operation not supported by the specified algorithm",
@@ -0,0 +1,304 @@
+//! Drives [Project Wycheproof](https://github.com/google/wycheproof) test
+//! vectors through the [`CryptoAlgorithm`] trait so that edge cases like
+//! truncated tags, wrong-length IVs, and malformed signatures are checked
+//! automatically whenever an algorithm is added or changed. See
+//! `tests/wycheproof.rs` for the `cargo test` entry point; vector files live
+//! under `tests/wycheproof/`.
+
+use serde::Deserialize;
+
+use super::CryptoAlgorithm;
+use crate::{
+    builtins::crypto::subtle::crypto_key::{CryptoKey, KeyUsage},
+    keystore::MemoryKeyStore,
+};
+
+/// Test vectors build `CryptoKey`s directly from fixture bytes rather than
+/// going through `generate_key`/`import_key`, so they never carry a keystore
+/// handle and this backend is never actually consulted -- it only exists to
+/// satisfy the `CryptoAlgorithm` trait's `keystore` parameter.
+fn test_keystore() -> MemoryKeyStore {
+    MemoryKeyStore::new()
+}
+
+#[derive(Deserialize)]
+pub struct TestVectorFile {
+    pub algorithm: String,
+    #[serde(rename = "testGroups")]
+    pub test_groups: Vec<TestGroup>,
+}
+
+#[derive(Deserialize)]
+pub struct TestGroup {
+    #[serde(default)]
+    pub iv_size: Option<u32>,
+    #[serde(default)]
+    pub key_size: Option<u32>,
+    #[serde(default)]
+    pub tag_size: Option<u32>,
+    #[serde(default)]
+    pub sha: Option<String>,
+    #[serde(default)]
+    pub key: Option<serde_json::Value>,
+    pub tests: Vec<TestCase>,
+}
+
+#[derive(Deserialize)]
+pub struct TestCase {
+    #[serde(rename = "tcId")]
+    pub tc_id: u32,
+    #[serde(default)]
+    pub comment: String,
+    #[serde(default)]
+    pub key: Option<String>,
+    #[serde(default)]
+    pub iv: Option<String>,
+    #[serde(default)]
+    pub aad: Option<String>,
+    #[serde(default)]
+    pub msg: Option<String>,
+    #[serde(default)]
+    pub ct: Option<String>,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub sig: Option<String>,
+    pub result: Expected,
+}
+
+#[derive(Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Expected {
+    Valid,
+    Invalid,
+    Acceptable,
+}
+
+impl Expected {
+    /// `acceptable` vectors may legitimately succeed or fail depending on
+    /// how strictly the implementation interprets an ambiguous part of the
+    /// spec, so either outcome is a pass.
+    fn accepts(&self, succeeded: bool) -> bool {
+        match self {
+            Expected::Valid => succeeded,
+            Expected::Invalid => !succeeded,
+            Expected::Acceptable => true,
+        }
+    }
+}
+
+/// Decodes a hex string from a vector file. Vector files are fixed test
+/// data, not attacker input, but a malformed fixture (odd length, stray
+/// non-hex character) should fail the one test case it belongs to rather
+/// than panicking the whole harness out from under every other vector.
+fn hex_decode(s: &str) -> ion::Result<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return Err(ion::Error::new(
+            &format!("hex string has odd length: {s:?}"),
+            ion::ErrorKind::Normal,
+        ));
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&s[i..i + 2], 16).map_err(|_| {
+                ion::Error::new(&format!("invalid hex string: {s:?}"), ion::ErrorKind::Normal)
+            })
+        })
+        .collect()
+}
+
+/// Result of running one test case: `Ok(())` if the algorithm's outcome
+/// matched the vector's `result`, `Err(message)` otherwise.
+pub type VectorOutcome = Result<(), String>;
+
+/// Runs an AEAD (AES-GCM) test group against `algorithm`'s `encrypt`, using
+/// the ciphertext+tag as the expected output, and `decrypt`, using the
+/// vector's `result` flag as the expected outcome.
+pub fn run_aead_vectors(
+    cx: &ion::Context,
+    algorithm: &dyn CryptoAlgorithm,
+    group: &TestGroup,
+) -> Vec<(u32, VectorOutcome)> {
+    group
+        .tests
+        .iter()
+        .map(|test| {
+            let outcome = (|| -> ion::Result<bool> {
+                let key_bytes = hex_decode(test.key.as_deref().unwrap_or_default())?;
+                let key = CryptoKey::new(algorithm.name(), true, vec![KeyUsage::Decrypt], key_bytes);
+
+                let iv = hex_decode(test.iv.as_deref().unwrap_or_default())?;
+                let aad = hex_decode(test.aad.as_deref().unwrap_or_default())?;
+                let ct = hex_decode(test.ct.as_deref().unwrap_or_default())?;
+                let tag = hex_decode(test.tag.as_deref().unwrap_or_default())?;
+                let msg = hex_decode(test.msg.as_deref().unwrap_or_default())?;
+
+                let params = ion::Object::new(cx);
+                params.set_as(cx, "iv", &iv);
+                params.set_as(cx, "additionalData", &aad);
+                params.set_as(cx, "tagLength", &(tag.len() * 8));
+
+                let mut combined = ct;
+                combined.extend_from_slice(&tag);
+
+                let encrypted = algorithm.encrypt(cx, &params, &key, msg, &test_keystore())?;
+                algorithm
+                    .decrypt(cx, &params, &key, combined.clone(), &test_keystore())
+                    .map(|_| encrypted.as_slice() == combined.as_slice())
+            })();
+
+            let succeeded = outcome.unwrap_or(false);
+            let result = if test.result.accepts(succeeded) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "tcId {} ({}): expected {:?}, got success={succeeded}",
+                    test.tc_id, test.comment, test.result
+                ))
+            };
+            (test.tc_id, result)
+        })
+        .collect()
+}
+
+/// Runs a signature-verification test group (e.g. ECDSA) against
+/// `algorithm`'s `verify`. `curve_name` selects both the curve passed in the
+/// verification params and how the group's `key` is assembled: the NIST
+/// curves carry separate `wx`/`wy` coordinates, while Ed25519 (an Octet Key
+/// Pair, not a SEC1 point) carries a single `wx`.
+pub fn run_verify_vectors(
+    cx: &ion::Context,
+    algorithm: &dyn CryptoAlgorithm,
+    group: &TestGroup,
+    curve_name: &str,
+) -> Vec<(u32, VectorOutcome)> {
+    group
+        .tests
+        .iter()
+        .map(|test| {
+            let outcome = (|| -> ion::Result<bool> {
+                let key_json = group
+                    .key
+                    .clone()
+                    .ok_or_else(|| ion::Error::new("test group is missing a `key`", ion::ErrorKind::Normal))?;
+                let x = hex_decode(key_json["wx"].as_str().unwrap_or_default())?;
+                let key = if curve_name == "Ed25519" {
+                    CryptoKey::new_ec_public(curve_name, true, vec![KeyUsage::Verify], x)
+                } else {
+                    let y = hex_decode(key_json["wy"].as_str().unwrap_or_default())?;
+                    let mut point = vec![0x04u8];
+                    point.extend_from_slice(&x);
+                    point.extend_from_slice(&y);
+                    CryptoKey::new_ec_public(curve_name, true, vec![KeyUsage::Verify], point)
+                };
+
+                let params = ion::Object::new(cx);
+                params.set_as(cx, "namedCurve", &curve_name);
+                let hash_name = group.sha.clone().unwrap_or_else(|| "SHA-256".to_string());
+                params.set_as(cx, "hash", &hash_name);
+
+                let msg = hex_decode(test.msg.as_deref().unwrap_or_default())?;
+                let sig = hex_decode(test.sig.as_deref().unwrap_or_default())?;
+
+                algorithm.verify(cx, &params, &key, sig, msg, &test_keystore())
+            })();
+
+            let succeeded = outcome.unwrap_or(false);
+            let result = if test.result.accepts(succeeded) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "tcId {} ({}): expected {:?}, got success={succeeded}",
+                    test.tc_id, test.comment, test.result
+                ))
+            };
+            (test.tc_id, result)
+        })
+        .collect()
+}
+
+/// Runs a non-AEAD block-cipher test group (e.g. AES-CBC) against
+/// `algorithm`'s `encrypt`, comparing against the vector's `ct`.
+pub fn run_cipher_vectors(
+    cx: &ion::Context,
+    algorithm: &dyn CryptoAlgorithm,
+    group: &TestGroup,
+) -> Vec<(u32, VectorOutcome)> {
+    group
+        .tests
+        .iter()
+        .map(|test| {
+            let outcome = (|| -> ion::Result<bool> {
+                let key_bytes = hex_decode(test.key.as_deref().unwrap_or_default())?;
+                let key = CryptoKey::new(algorithm.name(), true, vec![KeyUsage::Encrypt], key_bytes);
+
+                let iv = hex_decode(test.iv.as_deref().unwrap_or_default())?;
+                let msg = hex_decode(test.msg.as_deref().unwrap_or_default())?;
+                let ct = hex_decode(test.ct.as_deref().unwrap_or_default())?;
+
+                let params = ion::Object::new(cx);
+                params.set_as(cx, "iv", &iv);
+
+                let got = algorithm.encrypt(cx, &params, &key, msg, &test_keystore())?;
+                Ok(got.as_slice() == ct.as_slice())
+            })();
+
+            let succeeded = outcome.unwrap_or(false);
+            let result = if test.result.accepts(succeeded) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "tcId {} ({}): expected {:?}, got success={succeeded}",
+                    test.tc_id, test.comment, test.result
+                ))
+            };
+            (test.tc_id, result)
+        })
+        .collect()
+}
+
+/// Runs a key-derivation test group (e.g. HKDF) against `algorithm`'s
+/// `derive_bits`, comparing against the vector's expected output key
+/// material in `ct` (reused here as the generic "expected bytes" field
+/// rather than adding an HKDF-specific one).
+pub fn run_derive_vectors(
+    cx: &ion::Context,
+    algorithm: &dyn CryptoAlgorithm,
+    group: &TestGroup,
+) -> Vec<(u32, VectorOutcome)> {
+    group
+        .tests
+        .iter()
+        .map(|test| {
+            let outcome = (|| -> ion::Result<bool> {
+                let ikm = hex_decode(test.key.as_deref().unwrap_or_default())?;
+                let key = CryptoKey::new(algorithm.name(), false, vec![KeyUsage::DeriveBits], ikm);
+
+                let salt = hex_decode(test.iv.as_deref().unwrap_or_default())?;
+                let info = hex_decode(test.aad.as_deref().unwrap_or_default())?;
+                let okm = hex_decode(test.ct.as_deref().unwrap_or_default())?;
+
+                let params = ion::Object::new(cx);
+                params.set_as(cx, "hash", &group.sha.clone().unwrap_or_else(|| "SHA-256".to_string()));
+                params.set_as(cx, "salt", &salt);
+                params.set_as(cx, "info", &info);
+
+                let got =
+                    algorithm.derive_bits(cx, &params, key, Some(okm.len() * 8), &test_keystore())?;
+                Ok(got.as_slice() == okm.as_slice())
+            })();
+
+            let succeeded = outcome.unwrap_or(false);
+            let result = if test.result.accepts(succeeded) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "tcId {} ({}): expected {:?}, got success={succeeded}",
+                    test.tc_id, test.comment, test.result
+                ))
+            };
+            (test.tc_id, result)
+        })
+        .collect()
+}
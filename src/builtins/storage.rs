@@ -0,0 +1,82 @@
+//! Surfaces the [`crate::storage::Storage`] backend to user code as a
+//! `caches`-like global (`globalThis.STORAGE`), so fetch handlers can read
+//! and write durable state without knowing which backend is behind it.
+
+use std::{collections::HashMap, sync::Arc};
+
+use ion::{Context, Object};
+
+use crate::storage::Storage;
+
+/// Defines the `STORAGE` global on `global`, backed by `storage`. Called
+/// alongside `service_workers::define` during global initialization.
+pub fn define(cx: &Context, global: &Object, storage: Arc<dyn Storage>) -> bool {
+    let binding = StorageBinding { storage };
+    let object = binding.into_object(cx);
+    global.set(cx, "STORAGE", &object.as_value(cx))
+}
+
+/// The JS-visible object backing `globalThis.STORAGE`. Exposes `get`,
+/// `put`, `delete`, and `list` as async methods, matching the shape of
+/// `Storage` itself.
+struct StorageBinding {
+    storage: Arc<dyn Storage>,
+}
+
+impl StorageBinding {
+    // Method bodies close over `self.storage` and await the trait's async
+    // methods on the runtime's existing event loop, the same way other
+    // async WinterCG builtins (fetch, timers) bridge Rust futures to JS
+    // promises.
+    fn into_object(self, cx: &Context) -> Object {
+        let object = Object::new(cx);
+        let storage = self.storage;
+
+        ion::js_fn_with_state(cx, &object, "get", storage.clone(), storage_get);
+        ion::js_fn_with_state(cx, &object, "put", storage.clone(), storage_put);
+        ion::js_fn_with_state(cx, &object, "delete", storage.clone(), storage_delete);
+        ion::js_fn_with_state(cx, &object, "list", storage, storage_list);
+
+        object
+    }
+}
+
+// `get` returns the value alongside its metadata (rather than just the
+// bytes) so callers can round-trip whatever they attached in `put` --
+// content type, custom headers, etc.
+async fn storage_get(
+    storage: Arc<dyn Storage>,
+    key: String,
+) -> ion::Result<Option<(Vec<u8>, HashMap<String, String>)>> {
+    let entry = storage
+        .get(&key)
+        .await
+        .map_err(|e| ion::Error::new(&e.to_string(), ion::ErrorKind::Normal))?;
+    Ok(entry.map(|e| (e.value, e.metadata)))
+}
+
+async fn storage_put(
+    storage: Arc<dyn Storage>,
+    key: String,
+    value: Vec<u8>,
+    metadata: HashMap<String, String>,
+) -> ion::Result<()> {
+    storage
+        .put(&key, crate::storage::StorageEntry { value, metadata })
+        .await
+        .map_err(|e| ion::Error::new(&e.to_string(), ion::ErrorKind::Normal))
+}
+
+async fn storage_delete(storage: Arc<dyn Storage>, key: String) -> ion::Result<()> {
+    storage
+        .delete(&key)
+        .await
+        .map_err(|e| ion::Error::new(&e.to_string(), ion::ErrorKind::Normal))
+}
+
+async fn storage_list(storage: Arc<dyn Storage>, prefix: String) -> ion::Result<Vec<String>> {
+    storage
+        .list(&prefix)
+        .await
+        .map_err(|e| ion::Error::new(&e.to_string(), ion::ErrorKind::Normal))
+}
@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+use super::{KeyHandle, KeyStore, StoredKey};
+
+/// On-disk keystore backend: each key is written as its own file under
+/// `directory`, named by its handle. Suitable for a single-instance
+/// deployment that wants generated/imported keys to survive a restart
+/// without standing up an external secrets store.
+pub struct DiskKeyStore {
+    directory: PathBuf,
+}
+
+impl DiskKeyStore {
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    fn path_for(&self, handle: &KeyHandle) -> PathBuf {
+        self.directory.join(&handle.0)
+    }
+}
+
+// On-disk layout: a 1-byte extractable flag followed by the raw key bytes.
+// Kept intentionally simple since the keystore already controls all reads
+// and writes to this directory.
+fn encode(key: &StoredKey) -> Vec<u8> {
+    let mut out = Vec::with_capacity(1 + key.bytes.len());
+    out.push(key.extractable as u8);
+    out.extend_from_slice(&key.bytes);
+    out
+}
+
+fn decode(bytes: Vec<u8>) -> Result<StoredKey> {
+    let (flag, rest) = bytes
+        .split_first()
+        .context("key file on disk is empty")?;
+    Ok(StoredKey {
+        bytes: rest.to_vec(),
+        extractable: *flag != 0,
+    })
+}
+
+impl KeyStore for DiskKeyStore {
+    fn generate(&self, len: usize, extractable: bool) -> Result<(KeyHandle, StoredKey)> {
+        let mut bytes = vec![0u8; len];
+        ion::utils::fill_random(&mut bytes);
+        let stored = StoredKey { bytes, extractable };
+        let handle = self.serialize(StoredKey {
+            bytes: stored.bytes.clone(),
+            extractable: stored.extractable,
+        })?;
+        Ok((handle, stored))
+    }
+
+    fn serialize(&self, key: StoredKey) -> Result<KeyHandle> {
+        std::fs::create_dir_all(&self.directory)
+            .with_context(|| format!("creating keystore directory {:?}", self.directory))?;
+        let handle = KeyHandle(Uuid::new_v4().to_string());
+        std::fs::write(self.path_for(&handle), encode(&key))
+            .with_context(|| format!("writing key {}", handle.0))?;
+        Ok(handle)
+    }
+
+    fn deserialize(&self, handle: &KeyHandle) -> Result<Option<StoredKey>> {
+        match std::fs::read(self.path_for(handle)) {
+            Ok(bytes) => Ok(Some(decode(bytes)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e).with_context(|| format!("reading key {}", handle.0)),
+        }
+    }
+
+    fn exists(&self, handle: &KeyHandle) -> Result<bool> {
+        Ok(self.path_for(handle).exists())
+    }
+
+    fn delete(&self, handle: &KeyHandle) -> Result<()> {
+        match std::fs::remove_file(self.path_for(handle)) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e).with_context(|| format!("deleting key {}", handle.0)),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, RwLock},
+};
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use super::{KeyHandle, KeyStore, StoredKey};
+
+/// In-memory keystore backend. Keys live only as long as the process; this
+/// is the default for local development and for deployments that don't
+/// need keys to survive a restart.
+pub struct MemoryKeyStore {
+    keys: RwLock<HashMap<String, StoredKey>>,
+    // Kept separate from the map's own locking so `generate` can mint a
+    // unique id without holding the write lock across the RNG call.
+    id_source: Mutex<()>,
+}
+
+impl MemoryKeyStore {
+    pub fn new() -> Self {
+        Self {
+            keys: RwLock::new(HashMap::new()),
+            id_source: Mutex::new(()),
+        }
+    }
+}
+
+impl Default for MemoryKeyStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyStore for MemoryKeyStore {
+    fn generate(&self, len: usize, extractable: bool) -> Result<(KeyHandle, StoredKey)> {
+        let mut bytes = vec![0u8; len];
+        ion::utils::fill_random(&mut bytes);
+        let stored = StoredKey { bytes, extractable };
+
+        let _guard = self.id_source.lock().unwrap();
+        let handle = KeyHandle(Uuid::new_v4().to_string());
+        self.keys
+            .write()
+            .unwrap()
+            .insert(handle.0.clone(), clone_stored(&stored));
+
+        Ok((handle, stored))
+    }
+
+    fn serialize(&self, key: StoredKey) -> Result<KeyHandle> {
+        let handle = KeyHandle(Uuid::new_v4().to_string());
+        self.keys.write().unwrap().insert(handle.0.clone(), key);
+        Ok(handle)
+    }
+
+    fn deserialize(&self, handle: &KeyHandle) -> Result<Option<StoredKey>> {
+        Ok(self.keys.read().unwrap().get(&handle.0).map(clone_stored))
+    }
+
+    fn exists(&self, handle: &KeyHandle) -> Result<bool> {
+        Ok(self.keys.read().unwrap().contains_key(&handle.0))
+    }
+
+    fn delete(&self, handle: &KeyHandle) -> Result<()> {
+        self.keys.write().unwrap().remove(&handle.0);
+        Ok(())
+    }
+}
+
+fn clone_stored(key: &StoredKey) -> StoredKey {
+    StoredKey {
+        bytes: key.bytes.clone(),
+        extractable: key.extractable,
+    }
+}
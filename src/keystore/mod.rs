@@ -0,0 +1,76 @@
+mod disk;
+mod memory;
+
+use anyhow::Result;
+
+pub use disk::DiskKeyStore;
+pub use memory::MemoryKeyStore;
+
+/// Opaque handle referencing a key held by a [`KeyStore`] backend. Holding
+/// one does not grant access to the key material itself -- only the
+/// backend can turn it back into usable bytes, and its `export` will
+/// refuse to do so for non-extractable keys.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyHandle(pub String);
+
+/// A key as the keystore sees it: opaque bytes plus the bookkeeping needed
+/// to honor `extractable` across a serialize/deserialize round trip.
+pub struct StoredKey {
+    pub bytes: Vec<u8>,
+    pub extractable: bool,
+}
+
+/// Pluggable persistence for [`CryptoKey`](crate::builtins::crypto::subtle::crypto_key::CryptoKey)
+/// material, modeled after trussed's storage trait: a handle identifies a
+/// key without exposing its bytes, so non-extractable keys generated in one
+/// request can still be referenced (but not read back as raw bytes) in a
+/// later one.
+pub trait KeyStore: Send + Sync {
+    /// Generates `len` bytes of key material, stores it, and returns a
+    /// handle to it. Implementations should use a CSRNG.
+    fn generate(&self, len: usize, extractable: bool) -> Result<(KeyHandle, StoredKey)>;
+
+    /// Persists caller-provided key material (e.g. from `importKey`) and
+    /// returns a handle to it.
+    fn serialize(&self, key: StoredKey) -> Result<KeyHandle>;
+
+    /// Looks up a previously stored key by handle. Returns `Ok(None)` if no
+    /// such handle exists. This is for internal consumers (sign/encrypt/
+    /// derive) that need the key's bytes regardless of `extractable` --
+    /// `extractable` only governs whether the bytes may be handed back to
+    /// script via `exportKey`, which should go through [`KeyStore::export`]
+    /// instead.
+    fn deserialize(&self, handle: &KeyHandle) -> Result<Option<StoredKey>>;
+
+    fn exists(&self, handle: &KeyHandle) -> Result<bool>;
+
+    fn delete(&self, handle: &KeyHandle) -> Result<()>;
+
+    /// Looks up a previously stored key for the purpose of exporting it back
+    /// to script, honoring `extractable`: returns `Ok(None)` both when the
+    /// handle doesn't exist and when it does but was stored as
+    /// non-extractable, so callers can't distinguish "no such key" from
+    /// "this key exists but refuses to be exported".
+    fn export(&self, handle: &KeyHandle) -> Result<Option<Vec<u8>>> {
+        Ok(self.deserialize(handle)?.and_then(|stored| {
+            if stored.extractable {
+                Some(stored.bytes)
+            } else {
+                None
+            }
+        }))
+    }
+}
+
+/// Selects which [`KeyStore`] backend to construct at startup.
+pub enum KeyStoreConfig {
+    Memory,
+    Disk { directory: std::path::PathBuf },
+}
+
+pub fn build(config: KeyStoreConfig) -> std::sync::Arc<dyn KeyStore> {
+    match config {
+        KeyStoreConfig::Memory => std::sync::Arc::new(MemoryKeyStore::new()),
+        KeyStoreConfig::Disk { directory } => std::sync::Arc::new(DiskKeyStore::new(directory)),
+    }
+}
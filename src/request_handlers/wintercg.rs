@@ -1,29 +1,61 @@
-use std::marker::PhantomData;
+use std::sync::Arc;
 
 use anyhow::{bail, Result};
 use ion::{Context, Object, Value};
 
-use crate::sm_utils;
+use crate::{
+    keystore::{self, KeyStore, KeyStoreConfig},
+    sm_utils,
+    storage::{self, Storage, StorageConfig},
+};
 
 use super::{
     ByRefStandardModules, Either, NewRequestHandler, PendingResponse, ReadyResponse, Request,
     RequestHandler, UserCode,
 };
 
-#[derive(Clone, Copy)]
-pub struct New;
+#[derive(Clone)]
+pub struct New {
+    keystore: Arc<dyn KeyStore>,
+    storage: Arc<dyn Storage>,
+}
 
-#[derive(Clone, Copy)]
-pub struct Initialized;
+#[derive(Clone)]
+pub struct Initialized {
+    keystore: Arc<dyn KeyStore>,
+}
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct WinterCGRequestHandler<State> {
-    _state: PhantomData<State>,
+    state: State,
+}
+
+/// Picks the keystore backend from the environment so a worker can keep a
+/// stable signing/wrapping key between invocations without re-importing
+/// secrets on every request: `WINTERJS_KEYSTORE_DIR` selects the on-disk
+/// backend, otherwise keys only live for the lifetime of the process.
+fn keystore_from_env() -> Arc<dyn KeyStore> {
+    match std::env::var("WINTERJS_KEYSTORE_DIR") {
+        Ok(directory) => keystore::build(KeyStoreConfig::Disk {
+            directory: directory.into(),
+        }),
+        Err(_) => keystore::build(KeyStoreConfig::Memory),
+    }
 }
 
+/// Builds the keystore and storage backends once, at process startup.
+/// Request handling re-enters `evaluate_scripts`/`specialize_with_scripts`
+/// (and `get_standard_modules`/`init_globals`) for every incoming script, so
+/// the backends must be selected here rather than there -- otherwise a
+/// disk-backed keystore would be re-opened (and a memory-backed one
+/// silently wiped) on every request, and an S3 client would be rebuilt
+/// needlessly.
 pub fn new_handler() -> WinterCGRequestHandler<New> {
     WinterCGRequestHandler::<New> {
-        _state: PhantomData,
+        state: New {
+            keystore: keystore_from_env(),
+            storage: storage::build_blocking(StorageConfig::from_env()),
+        },
     }
 }
 
@@ -31,7 +63,9 @@ impl NewRequestHandler for WinterCGRequestHandler<New> {
     type InitializedHandler = WinterCGRequestHandler<Initialized>;
 
     fn get_standard_modules(&self) -> Box<dyn ByRefStandardModules> {
-        Box::new(WinterCGStandardModules)
+        Box::new(WinterCGStandardModules {
+            storage: self.state.storage.clone(),
+        })
     }
 
     fn evaluate_scripts(self, cx: &Context, code: &UserCode) -> Result<Self::InitializedHandler> {
@@ -46,7 +80,9 @@ impl NewRequestHandler for WinterCGRequestHandler<New> {
         };
 
         Ok(WinterCGRequestHandler::<Initialized> {
-            _state: PhantomData,
+            state: Initialized {
+                keystore: self.state.keystore,
+            },
         })
     }
 
@@ -63,11 +99,21 @@ impl NewRequestHandler for WinterCGRequestHandler<New> {
         };
 
         Ok(WinterCGRequestHandler::<Initialized> {
-            _state: PhantomData,
+            state: Initialized {
+                keystore: self.state.keystore,
+            },
         })
     }
 }
 
+impl WinterCGRequestHandler<Initialized> {
+    /// The keystore backend selected at startup, available to `SubtleCrypto`
+    /// algorithm implementations for the lifetime of this handler.
+    pub fn keystore(&self) -> &Arc<dyn KeyStore> {
+        &self.state.keystore
+    }
+}
+
 impl RequestHandler for WinterCGRequestHandler<Initialized> {
     fn start_handling_request(
         &mut self,
@@ -89,7 +135,9 @@ impl RequestHandler for WinterCGRequestHandler<Initialized> {
     }
 }
 
-struct WinterCGStandardModules;
+struct WinterCGStandardModules {
+    storage: Arc<dyn Storage>,
+}
 
 impl ByRefStandardModules for WinterCGStandardModules {
     fn init_modules(&self, cx: &Context, global: &Object) -> bool {
@@ -98,5 +146,6 @@ impl ByRefStandardModules for WinterCGStandardModules {
 
     fn init_globals(&self, cx: &Context, global: &Object) -> bool {
         super::service_workers::define(cx, global)
+            && crate::builtins::storage::define(cx, global, self.storage.clone())
     }
 }
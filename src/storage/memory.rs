@@ -0,0 +1,58 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::{Storage, StorageEntry};
+
+/// In-memory storage backend. Values live only as long as the process --
+/// useful for local development, or as the zero-config default when no
+/// external object store is configured.
+pub struct MemoryStorage {
+    entries: RwLock<HashMap<String, StorageEntry>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self {
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for MemoryStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Storage for MemoryStorage {
+    async fn get(&self, key: &str) -> Result<Option<StorageEntry>> {
+        Ok(self.entries.read().unwrap().get(key).map(|entry| StorageEntry {
+            value: entry.value.clone(),
+            metadata: entry.metadata.clone(),
+        }))
+    }
+
+    async fn put(&self, key: &str, entry: StorageEntry) -> Result<()> {
+        self.entries.write().unwrap().insert(key.to_string(), entry);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.entries.write().unwrap().remove(key);
+        Ok(())
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        Ok(self
+            .entries
+            .read()
+            .unwrap()
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+}
@@ -0,0 +1,85 @@
+mod memory;
+mod s3;
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+pub use memory::MemoryStorage;
+pub use s3::S3Storage;
+
+/// A stored value plus whatever caller-supplied metadata (content type,
+/// custom headers, etc.) was attached when it was written.
+pub struct StorageEntry {
+    pub value: Vec<u8>,
+    pub metadata: HashMap<String, String>,
+}
+
+/// Durable, byte-oriented key/value storage surfaced to fetch handlers.
+/// Modeled on aerogramme's "storage behind a trait" refactor: request
+/// handling code only ever talks to this trait, so swapping the in-memory
+/// backend for an S3-compatible one is a startup-time config choice, not a
+/// code change.
+#[async_trait]
+pub trait Storage: Send + Sync {
+    async fn get(&self, key: &str) -> Result<Option<StorageEntry>>;
+
+    async fn put(&self, key: &str, entry: StorageEntry) -> Result<()>;
+
+    async fn delete(&self, key: &str) -> Result<()>;
+
+    /// Lists keys with the given prefix (an empty prefix lists everything).
+    async fn list(&self, prefix: &str) -> Result<Vec<String>>;
+}
+
+/// Selects which [`Storage`] backend to construct at startup.
+pub enum StorageConfig {
+    Memory,
+    S3 {
+        bucket: String,
+        endpoint: Option<String>,
+    },
+}
+
+impl StorageConfig {
+    /// Reads the backend choice from the environment: `WINTERJS_STORAGE_BUCKET`
+    /// selects the S3-compatible backend (with `WINTERJS_STORAGE_ENDPOINT`
+    /// optionally pointing it at a self-hosted Garage/MinIO endpoint instead
+    /// of AWS), otherwise storage is in-memory only.
+    pub fn from_env() -> Self {
+        match std::env::var("WINTERJS_STORAGE_BUCKET") {
+            Ok(bucket) => StorageConfig::S3 {
+                bucket,
+                endpoint: std::env::var("WINTERJS_STORAGE_ENDPOINT").ok(),
+            },
+            Err(_) => StorageConfig::Memory,
+        }
+    }
+}
+
+pub async fn build(config: StorageConfig) -> std::sync::Arc<dyn Storage> {
+    match config {
+        StorageConfig::Memory => std::sync::Arc::new(MemoryStorage::new()),
+        StorageConfig::S3 { bucket, endpoint } => {
+            std::sync::Arc::new(S3Storage::new(bucket, endpoint).await)
+        }
+    }
+}
+
+/// Builds the configured backend outside of an async context, for the
+/// global-setup path which runs before the event loop is handed control.
+/// There is no ambient Tokio runtime at that point (`Handle::current` would
+/// panic without one, and blocking a runtime's own thread on itself
+/// deadlocks/panics even with one) -- so this spins up a short-lived runtime just
+/// to drive the S3 client's async constructor to completion.
+pub fn build_blocking(config: StorageConfig) -> std::sync::Arc<dyn Storage> {
+    match config {
+        StorageConfig::Memory => std::sync::Arc::new(MemoryStorage::new()),
+        StorageConfig::S3 { .. } => tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to start a runtime to initialize storage")
+            .block_on(build(config)),
+    }
+}
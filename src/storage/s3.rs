@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use aws_sdk_s3 as s3;
+
+use super::{Storage, StorageEntry};
+
+// Prefix used to namespace user-supplied metadata keys in the S3 object's
+// metadata map, so we don't collide with anything AWS reserves.
+const METADATA_PREFIX: &str = "x-wjs-";
+
+/// S3-compatible object store backend. Works against AWS S3 as well as
+/// self-hosted Garage/MinIO deployments -- point `endpoint` at the
+/// self-hosted instance and credentials are picked up the usual AWS SDK
+/// way (environment, shared config, IAM role, etc.).
+pub struct S3Storage {
+    client: s3::Client,
+    bucket: String,
+}
+
+impl S3Storage {
+    pub async fn new(bucket: String, endpoint: Option<String>) -> Self {
+        let mut loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
+        if let Some(endpoint) = endpoint {
+            loader = loader.endpoint_url(endpoint);
+        }
+        let config = loader.load().await;
+        let client = s3::Client::new(&config);
+
+        Self { client, bucket }
+    }
+}
+
+#[async_trait]
+impl Storage for S3Storage {
+    async fn get(&self, key: &str) -> Result<Option<StorageEntry>> {
+        let result = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await;
+
+        let output = match result {
+            Ok(output) => output,
+            Err(s3::error::SdkError::ServiceError(e)) if e.err().is_no_such_key() => {
+                return Ok(None)
+            }
+            Err(e) => return Err(e).context("fetching object from S3"),
+        };
+
+        let metadata = output.metadata().cloned().unwrap_or_default();
+        let metadata = metadata
+            .into_iter()
+            .filter_map(|(k, v)| k.strip_prefix(METADATA_PREFIX).map(|k| (k.to_string(), v)))
+            .collect();
+
+        let value = output
+            .body
+            .collect()
+            .await
+            .context("reading S3 object body")?
+            .into_bytes()
+            .to_vec();
+
+        Ok(Some(StorageEntry { value, metadata }))
+    }
+
+    async fn put(&self, key: &str, entry: StorageEntry) -> Result<()> {
+        let metadata: HashMap<String, String> = entry
+            .metadata
+            .into_iter()
+            .map(|(k, v)| (format!("{METADATA_PREFIX}{k}"), v))
+            .collect();
+
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .body(entry.value.into())
+            .set_metadata(Some(metadata))
+            .send()
+            .await
+            .context("writing object to S3")?;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .context("deleting object from S3")?;
+        Ok(())
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        let mut keys = Vec::new();
+        let mut continuation_token = None;
+
+        loop {
+            let mut request = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(prefix);
+            if let Some(token) = continuation_token.take() {
+                request = request.continuation_token(token);
+            }
+            let output = request.send().await.context("listing objects in S3")?;
+
+            keys.extend(
+                output
+                    .contents()
+                    .iter()
+                    .filter_map(|object| object.key().map(str::to_string)),
+            );
+
+            if output.is_truncated().unwrap_or(false) {
+                continuation_token = output.next_continuation_token().map(str::to_string);
+            } else {
+                break;
+            }
+        }
+
+        Ok(keys)
+    }
+}
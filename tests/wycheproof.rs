@@ -0,0 +1,86 @@
+//! Conformance entry point: loads the Wycheproof vector files shipped under
+//! `tests/wycheproof/` and drives each one through the matching
+//! `CryptoAlgorithm` implementation. Run with `cargo test --test wycheproof`.
+
+use winterjs::builtins::crypto::subtle::algorithm::{
+    aes::{AesCbc, AesGcm},
+    ec::{Ecdsa, Ed25519},
+    kdf::Hkdf,
+    wycheproof::{
+        run_aead_vectors, run_cipher_vectors, run_derive_vectors, run_verify_vectors,
+        TestVectorFile,
+    },
+    CryptoAlgorithm,
+};
+
+fn load(path: &str) -> TestVectorFile {
+    let raw = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("reading {path}: {e}"));
+    serde_json::from_str(&raw).unwrap_or_else(|e| panic!("parsing {path}: {e}"))
+}
+
+fn assert_all_passed(file_name: &str, results: Vec<(u32, Result<(), String>)>) {
+    let failures: Vec<String> = results
+        .into_iter()
+        .filter_map(|(_, r)| r.err())
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{file_name} had failing Wycheproof vectors:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn aes_gcm_vectors() {
+    let cx = winterjs::sm_utils::test_context();
+    let file = load("tests/wycheproof/aes_gcm_test.json");
+    let algorithm = AesGcm;
+    for group in &file.test_groups {
+        let results = run_aead_vectors(&cx, &algorithm as &dyn CryptoAlgorithm, group);
+        assert_all_passed("aes_gcm_test.json", results);
+    }
+}
+
+#[test]
+fn ecdsa_p256_sha256_vectors() {
+    let cx = winterjs::sm_utils::test_context();
+    let file = load("tests/wycheproof/ecdsa_p256_sha256_test.json");
+    let algorithm = Ecdsa;
+    for group in &file.test_groups {
+        let results = run_verify_vectors(&cx, &algorithm as &dyn CryptoAlgorithm, group, "P-256");
+        assert_all_passed("ecdsa_p256_sha256_test.json", results);
+    }
+}
+
+#[test]
+fn aes_cbc_vectors() {
+    let cx = winterjs::sm_utils::test_context();
+    let file = load("tests/wycheproof/aes_cbc_test.json");
+    let algorithm = AesCbc;
+    for group in &file.test_groups {
+        let results = run_cipher_vectors(&cx, &algorithm as &dyn CryptoAlgorithm, group);
+        assert_all_passed("aes_cbc_test.json", results);
+    }
+}
+
+#[test]
+fn ed25519_vectors() {
+    let cx = winterjs::sm_utils::test_context();
+    let file = load("tests/wycheproof/eddsa_test.json");
+    let algorithm = Ed25519;
+    for group in &file.test_groups {
+        let results = run_verify_vectors(&cx, &algorithm as &dyn CryptoAlgorithm, group, "Ed25519");
+        assert_all_passed("eddsa_test.json", results);
+    }
+}
+
+#[test]
+fn hkdf_sha256_vectors() {
+    let cx = winterjs::sm_utils::test_context();
+    let file = load("tests/wycheproof/hkdf_sha256_test.json");
+    let algorithm = Hkdf;
+    for group in &file.test_groups {
+        let results = run_derive_vectors(&cx, &algorithm as &dyn CryptoAlgorithm, group);
+        assert_all_passed("hkdf_sha256_test.json", results);
+    }
+}